@@ -2,270 +2,3369 @@
 #![feature(let_chains)]
 
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
+use proc_macro2::{Group, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::borrow::Cow;
 use syn::{
-    parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Error as SynError, Expr,
-    ExprAssign, Field, GenericArgument, Ident, Lit, PathArguments, Type,
+    ext::IdentExt, parse::Parse, parse_quote, parse_quote_spanned, spanned::Spanned, Attribute,
+    Data, DeriveInput, Error as SynError, Expr, Field, Fields, GenericArgument, GenericParam,
+    Generics, Ident, Index, Lit, LitStr, Member, Meta, MetaList, MetaNameValue, NestedMeta, Path,
+    PathArguments, PathSegment, Type, TypePath, Variant, Visibility,
 };
 
 #[proc_macro_derive(Builder, attributes(builder))]
 pub fn derive(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    TokenStream::from(match input.data {
-        Data::Struct(ref data) => StructBuilder::derive(&input, data),
-        _ => unimplemented!(),
-    })
+    TokenStream::from(expand(TokenStream2::from(input)))
 }
 
+// The derive on `proc_macro2` tokens, which unlike `proc_macro` ones can be
+// used outside of a macro, as the expansion tests do. A proc-macro crate can
+// only export its macros, so this stays private to the crate.
+fn expand(input: TokenStream2) -> TokenStream2 {
+    let input = match syn::parse2::<DeriveInput>(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error(),
+    };
+    match &input.data {
+        Data::Struct(data) => StructBuilder::derive(&input, None, &data.fields),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| StructBuilder::derive(&input, Some(variant), &variant.fields))
+            .collect(),
+        Data::Union(data) => {
+            SynError::new_spanned(data.union_token, "unions are not supported").to_compile_error()
+        }
+    }
+}
+
+// Generates the builder of a struct, or of a single variant when deriving on
+// an enum, in which case the variant is built as `Enum::Variant { .. }`.
 struct StructBuilder<'a> {
     input: &'a DeriveInput,
-    fields: Vec<FieldType<'a>>,
+    variant: Option<&'a Variant>,
+    options: StructOptions,
+    fields: Vec<BuilderField<'a>>,
+    roots: Roots,
 }
 
 impl<'a> StructBuilder<'a> {
-    fn derive(input: &DeriveInput, data: &DataStruct) -> TokenStream2 {
-        let generator = StructBuilder::analyze(input, data);
+    fn derive(input: &DeriveInput, variant: Option<&Variant>, fields: &Fields) -> TokenStream2 {
+        let generator = StructBuilder::analyze(input, variant, fields);
         match generator {
             Ok(gen) => gen.generate(),
             Err(err) => err.to_compile_error(),
         }
     }
 
-    fn analyze(input: &'a DeriveInput, data: &'a DataStruct) -> Result<Self, SynError> {
-        let fields = data
-            .fields
+    fn analyze(
+        input: &'a DeriveInput,
+        variant: Option<&'a Variant>,
+        fields: &'a Fields,
+    ) -> Result<Self, SynError> {
+        let mut errors = Errors::default();
+        let mut options = StructOptions::default();
+        options.parse(&input.attrs, &mut errors);
+        if let Some(variant) = variant {
+            // Every variant gets its own builder, which cannot all share one
+            // name.
+            let names = [options.name.as_ref(), options.constructor.name.as_ref()];
+            for name in names.into_iter().flatten() {
+                errors.push(SynError::new_spanned(
+                    name,
+                    "names can only be given on the variants of an enum",
+                ));
+            }
+            options.parse(&variant.attrs, &mut errors);
+            if options.default {
+                errors.push(SynError::new_spanned(
+                    variant,
+                    "`builder(default)` is not supported on enums",
+                ));
+            }
+        }
+
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| BuilderField::new(field, i, &options, &mut errors))
+            .collect::<Vec<_>>();
+
+        // Fields whose names only differ in underscores, such as `id` and
+        // `_id`, would share a variant of the error or a type parameter.
+        let mut generated: Vec<(Ident, &Ident)> = Vec::new();
+        for field in &fields {
+            let name = match &field.kind {
+                FieldType::Normal { ident, .. } if options.typestate => typestate_param(ident),
+                FieldType::Normal { ident, .. } => missing_variant(ident),
+                FieldType::SubBuilder { ident, .. } => invalid_variant(ident),
+                _ => continue,
+            };
+            if let Some((_, other)) = generated.iter().find(|(other, _)| *other == name) {
+                errors.push(SynError::new_spanned(
+                    field.ident(),
+                    format!(
+                        "`{}` would be generated for both `{}` and `{}`",
+                        name,
+                        other,
+                        field.ident(),
+                    ),
+                ));
+            }
+            generated.push((name, field.ident()));
+        }
+
+        // Clashes between the methods of the builder are reported at the
+        // field instead of as duplicate definitions in the generated code.
+        // Methods such as `reset_<setter>` share the span of the setter name,
+        // so only the first clash of a field is reported.
+        let builder_methods = options.builder_method_names(variant.is_some());
+        let mut methods: Vec<(Ident, &Ident)> = Vec::new();
+        for field in &fields {
+            for name in field.method_names(&options) {
+                if builder_methods.contains(&name) {
+                    errors.push(SynError::new_spanned(
+                        &name,
+                        format!("`{}` clashes with a method of the builder itself", name),
+                    ));
+                    break;
+                } else if let Some((_, other)) = methods.iter().find(|(method, _)| *method == name)
+                {
+                    errors.push(SynError::new_spanned(
+                        &name,
+                        format!("`{}` clashes with a method of field `{}`", name, other),
+                    ));
+                    break;
+                }
+                methods.push((name, field.ident()));
+            }
+        }
+        errors.finish()?;
+        Ok(Self {
+            input,
+            variant,
+            roots: Roots::new(options.no_std),
+            options,
+            fields,
+        })
+    }
+
+    fn builder_ident(&self) -> Ident {
+        match (&self.options.name, self.variant) {
+            (Some(name), _) => name.clone(),
+            (None, Some(variant)) => format_ident!("{}{}Builder", self.input.ident, variant.ident),
+            (None, None) => format_ident!("{}Builder", self.input.ident),
+        }
+    }
+
+    fn error_ident(&self) -> Ident {
+        format_ident!("{}Error", self.builder_ident())
+    }
+
+    fn constructor_ident(&self) -> Ident {
+        match (&self.options.constructor.name, self.variant) {
+            (Some(name), _) => name.clone(),
+            (None, Some(variant)) => format_ident!("{}_builder", snake_case(&variant.ident)),
+            (None, None) => format_ident!("builder"),
+        }
+    }
+
+    fn build_fn_ident(&self) -> Ident {
+        self.options.build_fn_ident()
+    }
+
+    // How the docs refer to the target, as `Enum::Variant` for enums.
+    fn target_name(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{}::{}", self.input.ident, variant.ident),
+            None => self.input.ident.to_string(),
+        }
+    }
+
+    fn gen_constructor_doc(&self) -> TokenStream2 {
+        if self.options.required_ctor {
+            return doc(&format!(
+                "Returns a new [`{}`] with the required fields set, in declaration order.",
+                self.builder_ident(),
+            ));
+        }
+        doc(&format!("Returns a new [`{}`].", self.builder_ident()))
+    }
+
+    // The builder is documented with `builder(doc = "...")` when given.
+    fn gen_builder_doc(&self) -> TokenStream2 {
+        if let Some(lit) = &self.options.doc {
+            return quote! {
+                #[doc = #lit]
+            };
+        }
+        let mut text = format!(
+            "Builder for [`{}`], returned by [`{}::{}`].",
+            self.target_name(),
+            self.input.ident,
+            self.constructor_ident(),
+        );
+        if !self.options.typestate && self.variant.is_none() {
+            text += &format!(
+                "\n\nOther builders can hold it with `#[builder(sub_builder)]`, \
+                 editing it with a [`{}Edit`] closure.",
+                self.builder_ident(),
+            );
+        }
+        doc(&text)
+    }
+
+    fn gen_build_doc(&self) -> TokenStream2 {
+        let mut text = format!(
+            "Builds a [`{}`] from the fields set so far.",
+            self.target_name()
+        );
+        if !self.options.typestate || self.has_validation() {
+            text += &format!(
+                "\n\nFails with [`{}`] when a required field is not set or a value is rejected.",
+                self.error_ident(),
+            );
+        }
+        doc(&text)
+    }
+
+    // The derives requested with `builder(derive(...))`, plus `Clone` which
+    // the immutable pattern needs.
+    fn gen_derive(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        let mut derive: Vec<TokenStream2> = self
+            .options
+            .derive
             .iter()
-            .map(FieldType::new)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self { input, fields })
+            .map(ToTokens::to_token_stream)
+            .collect();
+        let has_clone = self.options.derive.iter().any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Clone")
+        });
+        if self.options.pattern == Pattern::Immutable && !self.options.typestate && !has_clone {
+            derive.push(quote! { #core::clone::Clone });
+        }
+        // Fields missing from the input keep the value they have in an empty
+        // builder.
+        let serde = self.options.serde.then(|| {
+            derive.push(quote! { ::serde::Deserialize });
+            quote! {
+                #[serde(default)]
+            }
+        });
+        if derive.is_empty() {
+            return quote! {};
+        }
+        quote! {
+            #[derive(#(#derive),*)]
+            #serde
+        }
+    }
+
+    fn gen_construct(&self) -> TokenStream2 {
+        let target = &self.input.ident;
+        let path = match self.variant {
+            Some(variant) => {
+                let variant = &variant.ident;
+                quote! { #target::#variant }
+            }
+            None => quote! { #target },
+        };
+        let members = fields_map(&self.fields, |field| {
+            let (member, ident) = (&field.member, field.ident());
+            match member {
+                Member::Named(named) if named == ident => quote! { #ident, },
+                _ => quote! { #member: #ident, },
+            }
+        });
+
+        quote! {
+            #path {
+                #members
+            }
+        }
     }
 
     fn generate(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        if self.options.typestate {
+            return self.generate_typestate();
+        }
+
         let target = &self.input.ident;
-        let builder = format_ident!("{}Builder", target);
+        let builder = self.builder_ident();
+        let error = self.error_ident();
+        let constructor = self.constructor_ident();
+        let build_fn = self.build_fn_ident();
+        let vis = &self.options.vis;
+        let generics = &self.input.generics;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let partial = self.gen_partial();
         let init = self.gen_init();
         let setters = self.gen_setters();
         let build = self.gen_build();
+        let error_enum = self.gen_error();
+        let receiver = self.options.pattern.receiver();
+        let derive = self.gen_derive();
+        let pattern = self.options.pattern;
+        let getters = self.gen_getters();
+        let merge = self.gen_merge();
+        let build_const = self.gen_build_const();
+        let (build, build_fns, edit_trait) = self.gen_sub_builder_api(build);
+        // Used by `missing_fields` and by the `missing_fields` of outer builders.
+        let missing_fields = match self.variant.is_none() || self.options.getters {
+            true => self.gen_missing_fields(),
+            false => quote! {},
+        };
+        let resets = fields_map(&self.fields, |field| field.gen_reset(pattern, false));
+        let clear_doc = doc("Unsets every field, as in a new builder.");
+        let clear = pattern.setter(
+            &self.roots,
+            clear_doc,
+            quote! { clear },
+            quote! {},
+            |this| {
+                fields_map(&self.fields, |field| {
+                    if field.is_computed() {
+                        return quote! {};
+                    }
+                    let ident = field.ident();
+                    let init = field.kind.init(&self.roots);
+                    quote! {
+                        #this.#ident = #init;
+                    }
+                })
+            },
+        );
+        let from_target = self.gen_from_target(
+            quote! { #builder #ty_generics },
+            fields_map(&self.fields, |field| {
+                let ident = field.ident();
+                match &field.kind {
+                    FieldType::Normal { .. } | FieldType::Default { .. } => quote! {
+                        #ident: #core::option::Option::Some(#ident),
+                    },
+                    FieldType::Option { .. } | FieldType::Each { .. } => quote! {
+                        #ident,
+                    },
+                    FieldType::SubBuilder { .. } => quote! {
+                        #ident: #core::convert::From::from(#ident),
+                    },
+                    FieldType::Computed { .. } => quote! {},
+                }
+            }),
+        );
+
+        let constructor_doc = self.gen_constructor_doc();
+        let builder_doc = self.gen_builder_doc();
+        let build_doc = self.gen_build_doc();
+        // `Default::default` cannot be called in a const context.
+        let (constness, new_builder) = match self.options.constant {
+            true => (quote! { const }, quote! { #builder { #init } }),
+            false => (quote! {}, quote! { #core::default::Default::default() }),
+        };
 
         quote! {
-            impl #target {
-                pub fn builder() -> #builder {
-                    #builder {
-                        #init
-                    }
+            impl #impl_generics #target #ty_generics #where_clause {
+                #constructor_doc
+                #vis #constness fn #constructor() -> #builder #ty_generics {
+                    #new_builder
                 }
             }
 
-            pub struct #builder {
+            #builder_doc
+            #derive
+            #vis struct #builder #generics #where_clause {
                 #partial
             }
 
-            impl #builder {
+            impl #impl_generics #core::default::Default for #builder #ty_generics #where_clause {
+                fn default() -> Self {
+                    #builder {
+                        #init
+                    }
+                }
+            }
+
+            impl #impl_generics #builder #ty_generics #where_clause {
                 #setters
+                #getters
+                #resets
+                #clear
+                #merge
 
-                pub fn build(&mut self)
-                    -> std::result::Result<#target, std::boxed::Box<dyn std::error::Error>>
-                {
+                #build_doc
+                #vis fn #build_fn(#receiver) -> #core::result::Result<#target #ty_generics, #error> {
                     #build
                 }
+
+                #build_const
+                #build_fns
+                #missing_fields
             }
+
+            #edit_trait
+            #from_target
+            #error_enum
         }
     }
 
-    fn gen_partial(&self) -> TokenStream2 {
-        fields_map(&self.fields, |field| match field {
-            FieldType::Normal { ident, ty } => quote! {
-                #ident: std::option::Option<#ty>,
-            },
-            FieldType::Option { ident, ty } => quote! {
-                #ident: std::option::Option<#ty>,
-            },
-            FieldType::Each { ident, ty, .. } => quote! {
-                #ident: std::vec::Vec<#ty>,
-            },
-        })
-    }
+    // The API an outer builder uses for this one when it is a `sub_builder`,
+    // which does not depend on the pattern of either builder: `__edit` runs a
+    // closure taking the builder the way its own setters do, and the builder
+    // is built by value or by reference. Builds by reference clone the
+    // fields, and clone the whole builder with the owned pattern.
+    //
+    // With the other patterns, `build` goes through `__build_ref` too, which
+    // gets the body of `build`.
+    //
+    // A builder cannot know whether an outer one holds it, so every struct
+    // builder has this API. The builder of a variant has none, since the field
+    // of an outer builder can only name the builder of a struct.
+    fn gen_sub_builder_api(
+        &self,
+        build: TokenStream2,
+    ) -> (TokenStream2, TokenStream2, TokenStream2) {
+        if self.variant.is_some() {
+            return (build, quote! {}, quote! {});
+        }
+        let core = &self.roots.core;
+        let target = &self.input.ident;
+        let builder = self.builder_ident();
+        let error = self.error_ident();
+        let build_fn = self.build_fn_ident();
+        let vis = &self.options.vis;
+        let generics = &self.input.generics;
+        let (_, ty_generics, where_clause) = generics.split_for_impl();
+        let result = quote! { #core::result::Result<#target #ty_generics, #error> };
+        let pattern = self.options.pattern;
 
-    fn gen_init(&self) -> TokenStream2 {
-        fields_map(&self.fields, |field| match field {
-            FieldType::Normal { ident, .. } => quote! {
-                #ident: std::option::Option::None,
-            },
-            FieldType::Option { ident, .. } => quote! {
-                #ident: std::option::Option::None,
-            },
-            FieldType::Each { ident, .. } => quote! {
-                #ident: std::vec::Vec::new(),
-            },
-        })
-    }
+        let (build, closure, edit, build_fns) = match pattern {
+            Pattern::Owned => {
+                let mut clone_generics = generics.clone();
+                clone_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! {
+                        for<'__builder> #builder #ty_generics: #core::clone::Clone
+                    });
+                let clone_bound = &clone_generics.where_clause;
+                (
+                    build,
+                    quote! { #builder #ty_generics },
+                    quote! {
+                        *self = f(#core::mem::take(self));
+                    },
+                    quote! {
+                        #[doc(hidden)]
+                        pub fn __build_owned(self) -> #result {
+                            self.#build_fn()
+                        }
 
-    fn gen_setters(&self) -> TokenStream2 {
-        fields_map(&self.fields, |field| match field {
-            FieldType::Normal { ident, ty } => quote! {
-                pub fn #ident(&mut self, #ident: #ty) -> &mut Self {
-                    self.#ident = std::option::Option::Some(#ident);
-                    self
-                }
-            },
-            FieldType::Option { ident, ty } => quote! {
-                pub fn #ident(&mut self, #ident: #ty) -> &mut Self {
-                    self.#ident = std::option::Option::Some(#ident);
-                    self
-                }
-            },
-            FieldType::Each { ident, ty, each } => quote! {
-                pub fn #each(&mut self, #each: #ty) -> &mut Self {
-                    self.#ident.push(#each);
-                    self
-                }
-            },
-        })
+                        #[doc(hidden)]
+                        pub fn __build_ref(&self) -> #result #clone_bound {
+                            #core::clone::Clone::clone(self).#build_fn()
+                        }
+                    },
+                )
+            }
+            _ => {
+                let closure = match pattern {
+                    Pattern::Mutable => quote! { &'__edit mut #builder #ty_generics },
+                    _ => quote! { #builder #ty_generics },
+                };
+                let edit = match pattern {
+                    Pattern::Mutable => quote! {
+                        f(self);
+                    },
+                    _ => quote! {
+                        *self = f(#core::mem::take(self));
+                    },
+                };
+                (
+                    quote! { self.__build_ref() },
+                    closure,
+                    edit,
+                    quote! {
+                        #[doc(hidden)]
+                        pub fn __build_owned(self) -> #result {
+                            self.__build_ref()
+                        }
+
+                        #[doc(hidden)]
+                        pub fn __build_ref(&self) -> #result {
+                            #build
+                        }
+                    },
+                )
+            }
+        };
+
+        let edit_ident = format_ident!("{}Edit", builder);
+        let mut impl_generics = generics.clone();
+        impl_generics.params.push(parse_quote! { __F });
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! {
+                __F: for<'__edit> #core::ops::FnOnce(#closure) -> #closure
+            });
+        let (edit_impl_generics, _, edit_where_clause) = impl_generics.split_for_impl();
+        let edit_doc = doc(&format!(
+            "Closures editing a [`{}`], as taken by the setters of outer builders holding it.",
+            builder,
+        ));
+
+        let build_fns = quote! {
+            #[doc(hidden)]
+            pub fn __edit(&mut self, f: impl #edit_ident #ty_generics) {
+                #edit
+            }
+
+            #build_fns
+        };
+        let edit_trait = quote! {
+            #edit_doc
+            #vis trait #edit_ident #generics: for<'__edit> #core::ops::FnOnce(#closure) -> #closure
+            #where_clause
+            {
+            }
+
+            impl #edit_impl_generics #edit_ident #ty_generics for __F #edit_where_clause {}
+        };
+        (build, build_fns, edit_trait)
     }
 
-    fn gen_build(&self) -> TokenStream2 {
-        let unwrapped = fields_map(&self.fields, |field| {
+    // `<field>_ref` getters of the fields marked with `builder(getters)`, or of
+    // every field along with `is_complete` and `missing_fields` when the struct
+    // is marked.
+    fn gen_getters(&self) -> TokenStream2 {
+        let Roots { core, alloc } = &self.roots;
+        let getters = fields_map(&self.fields, |field| {
+            let Some(name) = field.getter_name(self.options.getters) else {
+                return quote! {};
+            };
             let ident = field.ident();
-            let err_msg = format!("{:?} is required", &ident);
-
-            match field {
-                FieldType::Normal { ident, .. } => quote! {
-                    let #ident = if let std::option::Option::Some(x) = &self.#ident {
-                        x.clone()
-                    } else {
-                        return std::result::Result::Err(#err_msg.into());
-                    };
+            let doc = doc(&format!("Returns the value of `{}` set so far.", ident));
+            match &field.kind {
+                FieldType::Normal { ty, .. }
+                | FieldType::Default { ty, .. }
+                | FieldType::Option { ty, .. } => quote! {
+                    #doc
+                    pub fn #name(&self) -> #core::option::Option<&#ty> {
+                        self.#ident.as_ref()
+                    }
                 },
-                FieldType::Option { ident, .. } => quote! {
-                   let #ident = self.#ident.clone();
+                FieldType::Each { ty, .. } => quote! {
+                    #doc
+                    pub fn #name(&self) -> #core::option::Option<&#ty> {
+                        #core::option::Option::Some(&self.#ident)
+                    }
                 },
-                FieldType::Each { ident, .. } => quote! {
-                   let #ident = self.#ident.clone();
+                FieldType::SubBuilder { builder, .. } => quote! {
+                    #doc
+                    pub fn #name(&self) -> #core::option::Option<&#builder> {
+                        #core::option::Option::Some(&self.#ident)
+                    }
                 },
+                FieldType::Computed { .. } => quote! {},
             }
         });
+        if !self.options.getters {
+            return getters;
+        }
 
-        let target = &self.input.ident;
-        let idents = fields_map(&self.fields, |field| {
+        quote! {
+            #getters
+
+            /// Returns whether every required field is set, including those of
+            /// sub builders.
+            pub fn is_complete(&self) -> bool {
+                self.missing_fields().is_empty()
+            }
+
+            /// Returns the names of the required fields that are not set yet,
+            /// with the path of the field holding them for those of sub builders.
+            pub fn missing_fields(&self) -> #alloc::vec::Vec<#alloc::string::String> {
+                let mut missing = #alloc::vec::Vec::new();
+                self.__missing_fields("", &mut missing);
+                missing
+            }
+        }
+    }
+
+    // Pushes the names of the required fields that are not set, prefixed with
+    // `path`, which is how outer builders report those of their sub builders.
+    fn gen_missing_fields(&self) -> TokenStream2 {
+        let alloc = &self.roots.alloc;
+        let checks = fields_map(&self.fields, |field| match &field.kind {
+            FieldType::Normal { ident, .. } => {
+                let name = ident.unraw().to_string();
+                quote! {
+                    if self.#ident.is_none() {
+                        missing.push(#alloc::format!("{}{}", path, #name));
+                    }
+                }
+            }
+            FieldType::SubBuilder { ident, .. } => {
+                let name = format!("{{}}{}.", ident.unraw());
+                quote! {
+                    self.#ident.__missing_fields(&#alloc::format!(#name, path), missing);
+                }
+            }
+            _ => quote! {},
+        });
+        // A builder without required fields or sub builders leaves both unused.
+        let allow = checks.is_empty().then(|| {
+            quote! {
+                #[allow(unused_variables, clippy::ptr_arg)]
+            }
+        });
+        quote! {
+            #[doc(hidden)]
+            #allow
+            pub fn __missing_fields(
+                &self,
+                path: &str,
+                missing: &mut #alloc::vec::Vec<#alloc::string::String>,
+            ) {
+                #checks
+            }
+        }
+    }
+
+    // With `builder(serde)`, `merge` combines builders loaded from several
+    // sources. Fields set in `other` win, and items of `each` fields are
+    // appended. Sub builders are merged through `__merge`, which does the same
+    // whatever the pattern of the sub builder.
+    fn gen_merge(&self) -> TokenStream2 {
+        if !self.options.serde {
+            return quote! {};
+        }
+
+        let fields = fields_map(&self.fields, |field| {
             let ident = field.ident();
-            quote! { #ident, }
+            match &field.kind {
+                FieldType::Normal { .. } | FieldType::Default { .. } | FieldType::Option { .. } => {
+                    quote! {
+                        if other.#ident.is_some() {
+                            self.#ident = other.#ident;
+                        }
+                    }
+                }
+                FieldType::Each { ty, .. } => {
+                    let core = self.roots.core_at(ty.span());
+                    quote_spanned! {ty.span()=>
+                        #core::iter::Extend::extend(&mut self.#ident, other.#ident);
+                    }
+                }
+                FieldType::SubBuilder { .. } => quote! {
+                    self.#ident.__merge(other.#ident);
+                },
+                FieldType::Computed { .. } => quote! {},
+            }
         });
+        let doc =
+            doc("Sets every field that is set in `other`, appending the items of its collections.");
+        let merge = self.options.pattern.setter(
+            &self.roots,
+            doc,
+            quote! { merge },
+            quote! { other: Self },
+            |this| {
+                quote! {
+                    #this.__merge(other);
+                }
+            },
+        );
 
         quote! {
-            #unwrapped
+            #merge
 
-            std::result::Result::Ok(#target {
-                #idents
-            })
+            #[doc(hidden)]
+            pub fn __merge(&mut self, other: Self) {
+                #fields
+            }
         }
     }
-}
 
-enum FieldType<'a> {
-    Normal {
-        ident: &'a Option<Ident>,
-        ty: &'a Type,
-    },
-    Option {
-        ident: &'a Option<Ident>,
-        ty: &'a Type,
-    },
-    Each {
-        ident: &'a Option<Ident>,
-        ty: &'a Type,
-        each: Ident,
-    },
-}
+    // `From<Target>` for the builder and `Target::to_builder`, which start from
+    // every field of an existing value being set. Not available on enums, whose
+    // values may be of another variant.
+    fn gen_from_target(&self, builder: TokenStream2, fields: TokenStream2) -> TokenStream2 {
+        let core = &self.roots.core;
+        if self.variant.is_some() {
+            return quote! {};
+        }
 
-impl<'a> FieldType<'a> {
-    fn new(field: &'a Field) -> Result<Self, SynError> {
-        Self::check_option(field)
-            .or(Self::check_vec(field))
-            .unwrap_or({
-                let (ident, ty) = (&field.ident, &field.ty);
-                Ok(Self::Normal { ident, ty })
-            })
-    }
+        let target = &self.input.ident;
+        let vis = &self.options.vis;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+        // Computed fields are recomputed by `build`, so their values are dropped.
+        let members = fields_map(&self.fields, |field| {
+            let (member, ident) = (&field.member, field.ident());
+            match member {
+                _ if field.is_computed() => quote! { #member: _, },
+                Member::Named(named) if named == ident => quote! { #ident, },
+                _ => quote! { #member: #ident, },
+            }
+        });
+        let marker = self.has_marker().then(|| {
+            quote! {
+                __marker: #core::marker::PhantomData,
+            }
+        });
+        // The higher-ranked bound defers the `Clone` requirement to callers,
+        // so that targets which are not `Clone` still compile.
+        let mut generics = self.input.generics.clone();
+        generics.make_where_clause().predicates.push(parse_quote! {
+            for<'__builder> #target #ty_generics: #core::clone::Clone
+        });
+        let clone_bound = &generics.where_clause;
 
-    fn check_option(field: &'a Field) -> Option<Result<Self, SynError>> {
-        let (ident, ty) = (&field.ident, &field.ty);
-        first_generic_arg(ty, "Option").map(|ty| Ok(Self::Option { ident, ty }))
-    }
+        quote! {
+            impl #impl_generics #core::convert::From<#target #ty_generics> for #builder
+            #where_clause
+            {
+                fn from(value: #target #ty_generics) -> Self {
+                    let #target { #members } = value;
+                    Self {
+                        #fields
+                        #marker
+                    }
+                }
+            }
 
-    fn check_vec(field: &'a Field) -> Option<Result<Self, SynError>> {
-        let (ident, ty) = (&field.ident, &field.ty);
-        first_generic_arg(ty, "Vec").map(|gen_arg| {
-            if let Some(attr) = field.attrs.first()
-            && let Some(each) = attribute_each(attr) {
-                match each {
-                    Ok(each) => Ok(Self::Each {
-                        ident,
-                        ty: gen_arg,
-                        each,
-                    }),
-                    Err(err) => Err(err),
+            impl #impl_generics #target #ty_generics #where_clause {
+                /// Returns a builder with every field set from `self`.
+                #vis fn to_builder(&self) -> #builder #clone_bound {
+                    #core::convert::From::from(#core::clone::Clone::clone(self))
                 }
-            } else {
-                Ok(Self::Normal { ident, ty })
             }
-        })
+        }
     }
 
-    fn ident(&self) -> &'a Option<Ident> {
-        match self {
-            Self::Normal { ident, .. } => ident,
-            Self::Option { ident, .. } => ident,
-            Self::Each { ident, .. } => ident,
+    fn gen_partial(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        let partial = fields_map(&self.fields, |field| {
+            let attrs = field.gen_field_attrs();
+            match &field.kind {
+                FieldType::Normal { ident, ty }
+                | FieldType::Default { ident, ty, .. }
+                | FieldType::Option { ident, ty, .. } => quote! {
+                    #attrs
+                    #ident: #core::option::Option<#ty>,
+                },
+                FieldType::Each { ident, ty, .. } => quote! {
+                    #attrs
+                    #ident: #ty,
+                },
+                FieldType::SubBuilder { ident, builder, .. } => quote! {
+                    #attrs
+                    #ident: #builder,
+                },
+                FieldType::Computed { .. } => quote! {},
+            }
+        });
+        if !self.has_marker() {
+            return partial;
+        }
+        let target = &self.input.ident;
+        let (_, ty_generics, _) = self.input.generics.split_for_impl();
+        let skip = self.options.serde.then(|| {
+            quote! {
+                #[serde(skip)]
+            }
+        });
+        quote! {
+            #partial
+            #skip
+            __marker: #core::marker::PhantomData<fn() -> #target #ty_generics>,
         }
     }
-}
 
-fn fields_map<F>(fields: &[FieldType], f: F) -> TokenStream2
-where
-    F: FnMut(&FieldType) -> TokenStream2,
-{
-    fields.iter().flat_map(f).collect()
-}
+    // Typestate builders always have a marker, other builders only need one
+    // for the generic parameters used by skipped or computed fields alone.
+    fn has_marker(&self) -> bool {
+        self.options.typestate || !self.input.generics.params.is_empty()
+    }
 
-fn first_generic_arg<'a>(target: &'a Type, ty: &str) -> Option<&'a Type> {
-    match target {
-        Type::Path(path) => path.path.segments.first(),
-        _ => None,
+    fn gen_init(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        let init = fields_map(&self.fields, |field| {
+            if field.is_computed() {
+                return quote! {};
+            }
+            let ident = field.ident();
+            let init = field.kind.init(&self.roots);
+            quote! {
+                #ident: #init,
+            }
+        });
+        let marker = self.has_marker().then(|| {
+            quote! {
+                __marker: #core::marker::PhantomData,
+            }
+        });
+        quote! {
+            #init
+            #marker
+        }
     }
-    .and_then(|seg| (seg.ident == ty).then(|| &seg.arguments))
-    .and_then(|args| match args {
-        PathArguments::AngleBracketed(args) => args.args.first(),
-        _ => None,
-    })
-    .and_then(|arg| match arg {
-        GenericArgument::Type(ty) => Some(ty),
-        _ => None,
-    })
-}
 
-fn attribute_each(attr: &Attribute) -> Option<Result<Ident, SynError>> {
-    attr.parse_args::<ExprAssign>()
-        .ok()
-        .and_then(|ExprAssign { left, right, .. }| {
-            if let Expr::Path(path) = *left
-            && let Some(ident) = path.path.segments.first().map(|seg| &seg.ident)
-            {
-                if ident != "each" {
-                    return Some(Err(SynError::new_spanned(attr.parse_meta().unwrap(), "expected `builder(each = \"...\")`")))
+    fn gen_setters(&self) -> TokenStream2 {
+        fields_map(&self.fields, |field| {
+            field.gen_setters(self.options.pattern)
+        })
+    }
+
+    fn gen_build(&self) -> TokenStream2 {
+        let Roots { core, alloc } = &self.roots;
+        let error = self.error_ident();
+
+        let pattern = self.options.pattern;
+        let seed = self.gen_seed();
+        let unwrapped = self.fields_in_build_order(|field| match &field.kind {
+            FieldType::Normal { .. } | FieldType::SubBuilder { .. } => quote! {},
+            field => self.gen_unwrap(field, pattern.take(field.ident())),
+        });
+
+        // Sub builders are built up front and, like required fields, only
+        // yield a value when that succeeded.
+        let required = self
+            .fields
+            .iter()
+            .filter_map(|field| match &field.kind {
+                FieldType::Normal { ident, .. } | FieldType::SubBuilder { ident, .. } => {
+                    Some(ident)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let construct = self.gen_construct();
+
+        if required.is_empty() && !self.has_validation() {
+            return quote! {
+                #seed
+                #unwrapped
+
+                #core::result::Result::Ok(#construct)
+            };
+        }
+
+        let validate_builder = self.gen_validate_builder();
+        let finish = self.gen_finish();
+        if required.is_empty() {
+            return quote! {
+                let mut __errors = #alloc::vec::Vec::new();
+                #validate_builder
+                #seed
+                #unwrapped
+                #finish
+            };
+        }
+
+        let checks = fields_map(&self.fields, |field| match &field.kind {
+            FieldType::Normal { ident, .. } => {
+                let variant = missing_variant(ident);
+                quote! {
+                    if self.#ident.is_none() {
+                        __errors.push(#error::#variant);
+                    }
+                }
+            }
+            FieldType::SubBuilder { ident, .. } => {
+                let variant = invalid_variant(ident);
+                // Only the owned pattern may move the sub builder out, the
+                // others build it in place.
+                let built = match pattern {
+                    Pattern::Owned => quote! { self.#ident.__build_owned() },
+                    _ => quote! { self.#ident.__build_ref() },
+                };
+                quote! {
+                    let #ident = match #built {
+                        #core::result::Result::Ok(value) => #core::option::Option::Some(value),
+                        #core::result::Result::Err(__err) => {
+                            __errors.push(#error::#variant(__err));
+                            #core::option::Option::None
+                        }
+                    };
                 }
-            } else {
-                return None
             }
+            _ => quote! {},
+        });
+        let taken = self.fields.iter().filter_map(|field| match &field.kind {
+            FieldType::Normal { ident, .. } => Some(pattern.take(ident)),
+            FieldType::SubBuilder { ident, .. } => Some(quote! { #ident }),
+            _ => None,
+        });
+        let missing = self.fields.iter().filter_map(|field| match &field.kind {
+            FieldType::Normal { ident, .. } => Some(quote! { self.#ident.is_none() }),
+            FieldType::SubBuilder { ident, .. } => Some(quote! { #ident.is_none() }),
+            _ => None,
+        });
+        // The values are checked before they are moved out of the builder.
+        let validate_set = self
+            .fields
+            .iter()
+            .any(|field| field.options.validate.is_some())
+            .then(|| {
+                let validate_set = self.gen_validate_set();
+                quote! {
+                    if #(#missing)||* {
+                        #validate_set
+                    }
+                }
+            });
 
-            if let Expr::Lit(lit) = *right
-            && let Lit::Str(lit) = lit.lit
-            {
-                Some(Ok(format_ident!("{}", lit.value())))
+        quote! {
+            let mut __errors = #alloc::vec::Vec::new();
+            #validate_builder
+            #checks
+            #validate_set
+
+            if let (#(#core::option::Option::Some(#required),)*) = (#(#taken,)*) {
+                #seed
+                #unwrapped
+                #finish
             } else {
-                None
+                #core::result::Result::Err(#error::from_errors(__errors))
             }
-        })
+        }
+    }
+
+    fn has_validation(&self) -> bool {
+        self.options.build_fn.validate.is_some()
+            || self
+                .fields
+                .iter()
+                .any(|field| field.options.validate.is_some())
+    }
+
+    fn gen_validate_builder(&self) -> TokenStream2 {
+        let Roots { core, alloc } = &self.roots;
+        let error = self.error_ident();
+        let Some(validate) = &self.options.build_fn.validate else {
+            return quote! {};
+        };
+
+        quote! {
+            let __builder: &Self = &self;
+            if let #core::result::Result::Err(__err) = #validate(__builder) {
+                __errors.push(#error::Validation {
+                    field: #core::option::Option::None,
+                    message: #alloc::string::ToString::to_string(&__err),
+                });
+            }
+        }
+    }
+
+    // Runs the validator of `field` against `value`, a reference to its final
+    // value.
+    fn gen_validate_field(&self, field: &BuilderField, value: TokenStream2) -> TokenStream2 {
+        let Roots { core, alloc } = &self.roots;
+        let error = self.error_ident();
+        let Some(validate) = &field.options.validate else {
+            return quote! {};
+        };
+        let name = field.ident().to_string();
+        quote! {
+            if let #core::result::Result::Err(__err) = #validate(#value) {
+                __errors.push(#error::Validation {
+                    field: #core::option::Option::Some(#name),
+                    message: #alloc::string::ToString::to_string(&__err),
+                });
+            }
+        }
+    }
+
+    // When a required field is missing, validates the fields whose final value
+    // is known anyway: those that were set and those without a default. The
+    // other defaults may refer to the missing fields, like computed fields.
+    fn gen_validate_set(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        fields_map(&self.fields, |field| {
+            let ident = field.ident();
+            match &field.kind {
+                FieldType::Normal { .. } | FieldType::Default { .. } => {
+                    let validate = self.gen_validate_field(field, quote! { __value });
+                    quote! {
+                        if let #core::option::Option::Some(__value) = &self.#ident {
+                            #validate
+                        }
+                    }
+                }
+                FieldType::SubBuilder { .. } => {
+                    let validate = self.gen_validate_field(field, quote! { __value });
+                    quote! {
+                        if let #core::option::Option::Some(__value) = &#ident {
+                            #validate
+                        }
+                    }
+                }
+                FieldType::Option { default: None, .. } | FieldType::Each { default: None, .. } => {
+                    self.gen_validate_field(field, quote! { &self.#ident })
+                }
+                FieldType::Option {
+                    default: Some(_), ..
+                } => {
+                    let validate = self.gen_validate_field(field, quote! { &self.#ident });
+                    quote! {
+                        if self.#ident.is_some() {
+                            #validate
+                        }
+                    }
+                }
+                FieldType::Each { .. } | FieldType::Computed { .. } => quote! {},
+            }
+        })
+    }
+
+    // Runs the field validators against the final values, which are bound as
+    // locals by now, and returns every error that was collected on the way.
+    fn gen_finish(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        let error = self.error_ident();
+
+        let validate_fields = fields_map(&self.fields, |field| {
+            let ident = field.ident();
+            self.gen_validate_field(field, quote! { &#ident })
+        });
+        let construct = self.gen_construct();
+
+        quote! {
+            #validate_fields
+
+            if __errors.is_empty() {
+                #core::result::Result::Ok(#construct)
+            } else {
+                #core::result::Result::Err(#error::from_errors(__errors))
+            }
+        }
+    }
+
+    // With `builder(const)`, `<build>_const` builds the target in a const fn,
+    // panicking on a missing field. In a constant, the panic is a compile
+    // error. Fields are moved out one by one, so their types must not have
+    // destructors.
+    fn gen_build_const(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        if !self.options.constant {
+            return quote! {};
+        }
+
+        let target = &self.input.ident;
+        let vis = &self.options.vis;
+        let (_, ty_generics, _) = self.input.generics.split_for_impl();
+        let name = format_ident!("{}_const", self.build_fn_ident());
+        let unwrapped = self.fields_in_build_order(|field| {
+            let ident = field.ident();
+            match &field.kind {
+                FieldType::Normal { .. } => {
+                    let message = format!("`{}` is required", ident);
+                    quote! {
+                        let #ident = match self.#ident {
+                            #core::option::Option::Some(x) => x,
+                            #core::option::Option::None => #core::panic!(#message),
+                        };
+                    }
+                }
+                kind => self.gen_unwrap(kind, quote! { self.#ident }),
+            }
+        });
+        let construct = self.gen_construct();
+        let doc = doc(&format!(
+            "Builds a [`{}`] in a const context.\n\n\
+             Panics when a required field is not set, which fails the compilation \
+             when evaluated in a constant.",
+            self.target_name(),
+        ));
+
+        quote! {
+            #doc
+            #vis const fn #name(self) -> #target #ty_generics {
+                #unwrapped
+                #construct
+            }
+        }
+    }
+
+    // Like `fields_map`, but with the computed fields last so that their
+    // expressions can refer to every other field, whatever the order the
+    // fields are declared in.
+    fn fields_in_build_order<F>(&self, f: F) -> TokenStream2
+    where
+        F: FnMut(&BuilderField) -> TokenStream2,
+    {
+        let (computed, others): (Vec<_>, Vec<_>) =
+            self.fields.iter().partition(|field| field.is_computed());
+        others.into_iter().chain(computed).flat_map(f).collect()
+    }
+
+    fn gen_seed(&self) -> TokenStream2 {
+        let core = &self.roots.core;
+        if !self.options.default {
+            return quote! {};
+        }
+
+        let target = &self.input.ident;
+        let (_, ty_generics, _) = self.input.generics.split_for_impl();
+        quote! {
+            let __default: #target #ty_generics = #core::default::Default::default();
+        }
+    }
+
+    // Binds the final value of a field as a local, falling back to its default
+    // when nothing was set. Fields are bound in declaration order so a default
+    // expression can refer to the fields before it, computed fields last.
+    fn gen_unwrap(&self, field: &FieldType, partial: TokenStream2) -> TokenStream2 {
+        let core = &self.roots.core;
+        match field {
+            FieldType::Normal { ident, .. } | FieldType::SubBuilder { ident, .. } => quote! {
+                let #ident = #partial;
+            },
+            FieldType::Default { ident, default, .. } => {
+                let default = default.to_expr(&self.roots);
+                quote! {
+                    let #ident = match #partial {
+                        #core::option::Option::Some(x) => x,
+                        #core::option::Option::None => #default,
+                    };
+                }
+            }
+            FieldType::Option {
+                ident,
+                default: Some(default),
+                ..
+            } => {
+                let default = default.to_expr(&self.roots);
+                quote! {
+                    let #ident = match #partial {
+                        #core::option::Option::Some(x) => #core::option::Option::Some(x),
+                        #core::option::Option::None => #default,
+                    };
+                }
+            }
+            FieldType::Each {
+                ident,
+                ty,
+                default: Some(default),
+                ..
+            } => {
+                let default = default.to_expr(&self.roots);
+                let core = self.roots.core_at(ty.span());
+                quote_spanned! {ty.span()=>
+                    let #ident = {
+                        let mut #ident: #ty = #default;
+                        #core::iter::Extend::extend(&mut #ident, #partial);
+                        #ident
+                    };
+                }
+            }
+            FieldType::Option { ident, .. } | FieldType::Each { ident, .. } => quote! {
+                let #ident = #partial;
+            },
+            FieldType::Computed { ident, value, .. } => {
+                let value = value.to_expr(&self.roots);
+                quote! {
+                    let #ident = #value;
+                }
+            }
+        }
+    }
+
+    fn gen_error(&self) -> TokenStream2 {
+        let Roots { core, alloc } = &self.roots;
+        let error = self.error_ident();
+        let vis = &self.options.vis;
+
+        let (variants, names): (Vec<_>, Vec<_>) = self
+            .fields
+            .iter()
+            .filter_map(|field| match &field.kind {
+                FieldType::Normal { ident, .. } if !self.options.typestate => {
+                    Some((missing_variant(ident), ident.unraw().to_string()))
+                }
+                _ => None,
+            })
+            .unzip();
+        let error_impl = (!self.options.no_std).then(|| {
+            quote! {
+                impl std::error::Error for #error {}
+            }
+        });
+        let nested = self
+            .fields
+            .iter()
+            .filter_map(|field| match &field.kind {
+                FieldType::SubBuilder { ident, error, .. } => Some((ident, error)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let nested_variants = nested
+            .iter()
+            .map(|(ident, _)| invalid_variant(ident))
+            .collect::<Vec<_>>();
+        let nested_errors = nested.iter().map(|(_, error)| error);
+        let nested_names = nested.iter().map(|(ident, _)| ident.to_string());
+        let error_doc = doc(&format!(
+            "Error returned by [`{}::{}`].",
+            self.builder_ident(),
+            self.build_fn_ident(),
+        ));
+        let variant_docs = names
+            .iter()
+            .map(|name| doc(&format!("`{}` was not set.", name)));
+        let nested_docs = nested
+            .iter()
+            .map(|(ident, _)| doc(&format!("The builder of `{}` failed.", ident)));
+
+        quote! {
+            #error_doc
+            #[derive(#core::fmt::Debug, #core::clone::Clone, #core::cmp::PartialEq, #core::cmp::Eq)]
+            #vis enum #error {
+                #(
+                    #variant_docs
+                    #variants,
+                )*
+                #(
+                    #nested_docs
+                    #nested_variants(#nested_errors),
+                )*
+                /// A validator rejected a value.
+                Validation {
+                    /// The field whose value was rejected, or `None` for the
+                    /// validator of the whole builder.
+                    field: #core::option::Option<&'static str>,
+                    /// The message of the validator.
+                    message: #alloc::string::String,
+                },
+                /// Several errors, in the order they were found.
+                Multiple(#alloc::vec::Vec<#error>),
+            }
+
+            impl #error {
+                fn from_errors(mut errors: #alloc::vec::Vec<Self>) -> Self {
+                    if errors.len() == 1 {
+                        errors.remove(0)
+                    } else {
+                        #error::Multiple(errors)
+                    }
+                }
+
+                // Writes the error with every field name prefixed by `path`, the
+                // path of the builder within the builders it is nested in.
+                #[doc(hidden)]
+                pub fn __fmt_path(
+                    &self,
+                    path: #core::option::Option<&str>,
+                    f: &mut #core::fmt::Formatter,
+                ) -> #core::fmt::Result {
+                    let field = |name: &str| match path {
+                        #core::option::Option::Some(path) => #alloc::format!("{}.{}", path, name),
+                        #core::option::Option::None => #alloc::string::ToString::to_string(name),
+                    };
+                    match self {
+                        #(#error::#variants => #core::write!(f, "`{}` is required", field(#names)),)*
+                        #(
+                            #error::#nested_variants(err) => {
+                                err.__fmt_path(#core::option::Option::Some(&field(#nested_names)), f)
+                            }
+                        )*
+                        #error::Validation {
+                            field: #core::option::Option::Some(name),
+                            message,
+                        } => #core::write!(f, "invalid `{}`: {}", field(name), message),
+                        #error::Validation { message, .. } => match path {
+                            #core::option::Option::Some(path) => {
+                                #core::write!(f, "invalid `{}`: {}", path, message)
+                            }
+                            #core::option::Option::None => f.write_str(message),
+                        },
+                        #error::Multiple(errors) => {
+                            for (i, error) in errors.iter().enumerate() {
+                                if i > 0 {
+                                    f.write_str(", ")?;
+                                }
+                                error.__fmt_path(path, f)?;
+                            }
+                            #core::result::Result::Ok(())
+                        }
+                    }
+                }
+            }
+
+            impl #core::fmt::Display for #error {
+                fn fmt(&self, f: &mut #core::fmt::Formatter) -> #core::fmt::Result {
+                    self.__fmt_path(#core::option::Option::None, f)
+                }
+            }
+
+            #error_impl
+        }
+    }
+
+    // In typestate mode every required field gets its own type parameter on the
+    // builder, which is `()` until the setter is called and `(T,)` afterwards.
+    // `build` is only implemented once every parameter is in the set state.
+    fn generate_typestate(&self) -> TokenStream2 {
+        let Roots { core, alloc } = &self.roots;
+        let target = &self.input.ident;
+        let builder = self.builder_ident();
+        let constructor = self.constructor_ident();
+        let build_fn = self.build_fn_ident();
+        let vis = &self.options.vis;
+        let derive = self.gen_derive();
+        let generics = &self.input.generics;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let target_params = generic_params(generics);
+        let target_args = generic_args(generics);
+
+        let required = self
+            .fields
+            .iter()
+            .filter_map(|field| match &field.kind {
+                FieldType::Normal { ident, ty } => Some((ident, ty, typestate_param(ident))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let params = required
+            .iter()
+            .map(|(_, _, param)| param)
+            .collect::<Vec<_>>();
+        let unset = required.iter().map(|_| quote! { () }).collect::<Vec<_>>();
+        let set = required
+            .iter()
+            .map(|(_, ty, _)| quote! { (#ty,) })
+            .collect::<Vec<_>>();
+
+        let partial = fields_map(&self.fields, |field| {
+            let attrs = field.gen_field_attrs();
+            match &field.kind {
+                FieldType::Normal { ident, .. } => {
+                    let param = typestate_param(ident);
+                    quote! {
+                        #attrs
+                        #ident: #param,
+                    }
+                }
+                FieldType::Default { ident, ty, .. } | FieldType::Option { ident, ty, .. } => {
+                    quote! {
+                        #attrs
+                        #ident: #core::option::Option<#ty>,
+                    }
+                }
+                FieldType::Each { ident, ty, .. } => quote! {
+                    #attrs
+                    #ident: #ty,
+                },
+                FieldType::SubBuilder { .. } | FieldType::Computed { .. } => quote! {},
+            }
+        });
+
+        let init = fields_map(&self.fields, |field| match &field.kind {
+            FieldType::Normal { ident, .. } => quote! {
+                #ident: (),
+            },
+            FieldType::Computed { .. } => quote! {},
+            kind => {
+                let ident = field.ident();
+                let init = kind.init(&self.roots);
+                quote! {
+                    #ident: #init,
+                }
+            }
+        });
+
+        let setters = fields_map(&self.fields, |field| match &field.kind {
+            FieldType::Normal { ident, ty } => {
+                let name = field.setter_name();
+                let (arg, value) = field.options.setter.arg(&self.roots, ident, ty);
+                let args = required
+                    .iter()
+                    .map(|(other, _, param)| {
+                        if other == &ident {
+                            quote! { (#ty,) }
+                        } else {
+                            quote! { #param }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let moved = |value: TokenStream2| {
+                    fields_map(&self.fields, |other| {
+                        if other.is_computed() {
+                            return quote! {};
+                        }
+                        let other = other.ident();
+                        if other == ident {
+                            quote! { #ident: (#value,), }
+                        } else {
+                            quote! { #other: self.#other, }
+                        }
+                    })
+                };
+
+                let setter = (!field.options.setter.custom).then(|| {
+                    let moved = moved(value);
+                    let attrs = field.gen_setter_attrs(field.gen_docs(None));
+                    quote! {
+                        #attrs
+                        pub fn #name(self, #arg) -> #builder<#(#target_args,)* #(#args),*> {
+                            #builder {
+                                #moved
+                                __marker: #core::marker::PhantomData,
+                            }
+                        }
+                    }
+                });
+                let try_setter = field.options.try_setter.then(|| {
+                    let try_name = format_ident!("try_{}", name);
+                    let moved = moved(quote! { #ident });
+                    let attrs = field.gen_setter_attrs(try_setter_doc(ident));
+                    quote! {
+                        #attrs
+                        pub fn #try_name<__U: #core::convert::TryInto<#ty>>(
+                            self,
+                            #ident: __U,
+                        ) -> #core::result::Result<
+                            #builder<#(#target_args,)* #(#args),*>,
+                            <__U as #core::convert::TryInto<#ty>>::Error,
+                        > {
+                            let #ident = #core::convert::TryInto::try_into(#ident)?;
+                            #core::result::Result::Ok(#builder {
+                                #moved
+                                __marker: #core::marker::PhantomData,
+                            })
+                        }
+                    }
+                });
+                quote! {
+                    #setter
+                    #try_setter
+                }
+            }
+            _ => {
+                let setters = field.gen_setters(Pattern::Owned);
+                let reset = field.gen_reset(Pattern::Owned, true);
+                quote! {
+                    #setters
+                    #reset
+                }
+            }
+        });
+
+        // Required fields cannot be unset one by one as that changes the type
+        // of the builder, only all at once.
+        let from_target = self.gen_from_target(
+            quote! { #builder<#(#target_args,)* #(#set),*> },
+            fields_map(&self.fields, |field| {
+                let ident = field.ident();
+                match &field.kind {
+                    FieldType::Normal { .. } => quote! {
+                        #ident: (#ident,),
+                    },
+                    FieldType::Default { .. } => quote! {
+                        #ident: #core::option::Option::Some(#ident),
+                    },
+                    FieldType::Option { .. } | FieldType::Each { .. } => quote! {
+                        #ident,
+                    },
+                    FieldType::SubBuilder { .. } | FieldType::Computed { .. } => quote! {},
+                }
+            }),
+        );
+
+        // Every required field is known to be set here, so `build` can only fail
+        // when there are validators to run.
+        let seed = self.gen_seed();
+        let unwrapped = self.fields_in_build_order(|field| {
+            let ident = field.ident();
+            match &field.kind {
+                field @ FieldType::Normal { .. } => {
+                    self.gen_unwrap(field, quote! { self.#ident.0 })
+                }
+                field => self.gen_unwrap(field, quote! { self.#ident }),
+            }
+        });
+        let construct = self.gen_construct();
+
+        let build_doc = self.gen_build_doc();
+        let (build, error_enum) = if self.has_validation() {
+            let error = self.error_ident();
+            let validate_builder = self.gen_validate_builder();
+            let finish = self.gen_finish();
+            let build = quote! {
+                #build_doc
+                #vis fn #build_fn(self) -> #core::result::Result<#target #ty_generics, #error> {
+                    let mut __errors = #alloc::vec::Vec::new();
+                    #validate_builder
+                    #seed
+                    #unwrapped
+                    #finish
+                }
+            };
+            (build, self.gen_error())
+        } else {
+            let build = quote! {
+                #build_doc
+                #vis fn #build_fn(self) -> #target #ty_generics {
+                    #seed
+                    #unwrapped
+
+                    #construct
+                }
+            };
+            (build, quote! {})
+        };
+
+        let constructor_doc = self.gen_constructor_doc();
+        let builder_doc = self.gen_builder_doc();
+        let new_builder = if self.options.required_ctor {
+            let (args, values): (Vec<_>, Vec<_>) = self
+                .fields
+                .iter()
+                .filter_map(|field| match &field.kind {
+                    FieldType::Normal { ident, ty } => {
+                        let (arg, value) = field.options.setter.arg(&self.roots, ident, ty);
+                        Some((arg, quote! { #ident: (#value,), }))
+                    }
+                    _ => None,
+                })
+                .unzip();
+            let others = fields_map(&self.fields, |field| match &field.kind {
+                FieldType::Normal { .. } | FieldType::Computed { .. } => quote! {},
+                kind => {
+                    let ident = field.ident();
+                    let init = kind.init(&self.roots);
+                    quote! {
+                        #ident: #init,
+                    }
+                }
+            });
+            quote! {
+                #vis fn #constructor(#(#args),*) -> #builder<#(#target_args,)* #(#set),*> {
+                    #builder {
+                        #(#values)*
+                        #others
+                        __marker: #core::marker::PhantomData,
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #vis fn #constructor() -> #builder<#(#target_args,)* #(#unset),*> {
+                    #core::default::Default::default()
+                }
+            }
+        };
+
+        quote! {
+            impl #impl_generics #target #ty_generics #where_clause {
+                #constructor_doc
+                #new_builder
+            }
+
+            #builder_doc
+            #derive
+            #vis struct #builder<#(#target_params,)* #(#params),*> #where_clause {
+                #partial
+                __marker: #core::marker::PhantomData<fn() -> #target #ty_generics>,
+            }
+
+            impl #impl_generics #core::default::Default
+                for #builder<#(#target_args,)* #(#unset),*>
+            #where_clause
+            {
+                fn default() -> Self {
+                    #builder {
+                        #init
+                        __marker: #core::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl<#(#target_params,)* #(#params),*> #builder<#(#target_args,)* #(#params),*>
+            #where_clause
+            {
+                #setters
+
+                /// Unsets every field, as in a new builder.
+                pub fn clear(self) -> #builder<#(#target_args,)* #(#unset),*> {
+                    #core::default::Default::default()
+                }
+            }
+
+            impl #impl_generics #builder<#(#target_args,)* #(#set),*> #where_clause {
+                #build
+            }
+
+            #from_target
+            #error_enum
+        }
+    }
+}
+
+const STRUCT_KEYS: &[&str] = &[
+    "typestate",
+    "default",
+    "pattern",
+    "setter",
+    "build_fn",
+    "name",
+    "vis",
+    "derive",
+    "constructor",
+    "getters",
+    "try_setter",
+    "serde",
+    "no_std",
+    "doc",
+    "const",
+    "required_ctor",
+];
+const FIELD_KEYS: &[&str] = &[
+    "each",
+    "default",
+    "setter",
+    "validate",
+    "name",
+    "optional",
+    "required",
+    "getters",
+    "skip",
+    "compute",
+    "try_setter",
+    "sub_builder",
+    "field_attr",
+    "setter_attr",
+];
+
+struct StructOptions {
+    typestate: bool,
+    default: bool,
+    pattern: Pattern,
+    setter: SetterOptions,
+    build_fn: BuildFnOptions,
+    name: Option<Ident>,
+    vis: Visibility,
+    derive: Vec<Path>,
+    constructor: ConstructorOptions,
+    getters: bool,
+    try_setter: bool,
+    serde: bool,
+    no_std: bool,
+    doc: Option<LitStr>,
+    constant: bool,
+    required_ctor: bool,
+}
+
+impl Default for StructOptions {
+    fn default() -> Self {
+        Self {
+            typestate: false,
+            default: false,
+            pattern: Pattern::default(),
+            setter: SetterOptions::default(),
+            build_fn: BuildFnOptions::default(),
+            name: None,
+            vis: parse_quote! { pub },
+            derive: Vec::new(),
+            constructor: ConstructorOptions::default(),
+            getters: false,
+            try_setter: false,
+            serde: false,
+            no_std: false,
+            doc: None,
+            constant: false,
+            required_ctor: false,
+        }
+    }
+}
+
+impl StructOptions {
+    // Called once for the attributes of the type and, on enums, once more for
+    // those of the variant, which take precedence.
+    fn parse(&mut self, attrs: &[Attribute], errors: &mut Errors) {
+        let metas = builder_metas(attrs, errors);
+        let mut pattern_lit = None;
+        let mut getters = None;
+        let mut serde = None;
+        let mut constant = None;
+        for (key, meta) in builder_keys(&metas, "builder attribute", errors) {
+            let result = match key.as_str() {
+                "typestate" => expect_flag(&key, meta).map(|()| self.typestate = true),
+                // The constructor returns a typestate builder with every
+                // required field set.
+                "required_ctor" => expect_flag(&key, meta).map(|()| {
+                    self.required_ctor = true;
+                    self.typestate = true;
+                }),
+                "default" => expect_flag(&key, meta).map(|()| self.default = true),
+                "try_setter" => expect_flag(&key, meta).map(|()| self.try_setter = true),
+                "no_std" => expect_flag(&key, meta).map(|()| self.no_std = true),
+                "serde" if !cfg!(feature = "serde") => Err(SynError::new_spanned(
+                    meta,
+                    "`serde` requires the `serde` feature of derive_builder",
+                )),
+                "serde" => expect_flag(&key, meta).map(|()| {
+                    self.serde = true;
+                    serde = Some(meta);
+                }),
+                "const" => expect_flag(&key, meta).map(|()| {
+                    self.constant = true;
+                    constant = Some(meta);
+                }),
+                "getters" => expect_flag(&key, meta).map(|()| {
+                    self.getters = true;
+                    getters = Some(meta);
+                }),
+                "pattern" => expect_lit_str(&key, meta).and_then(|lit| {
+                    self.pattern = match lit.value().as_str() {
+                        "mutable" => Pattern::Mutable,
+                        "owned" => Pattern::Owned,
+                        "immutable" => Pattern::Immutable,
+                        _ => {
+                            return Err(SynError::new_spanned(
+                                lit,
+                                "expected \"mutable\", \"owned\" or \"immutable\"",
+                            ))
+                        }
+                    };
+                    pattern_lit = Some(lit);
+                    Ok(())
+                }),
+                "setter" => expect_list(&key, meta).and_then(|list| {
+                    let setter = SetterOptions::new(list, errors);
+                    if let Some(name) = &setter.name {
+                        return Err(SynError::new_spanned(
+                            name,
+                            "`setter(name)` can only be used on fields",
+                        ));
+                    }
+                    self.setter = setter.merge(&self.setter);
+                    Ok(())
+                }),
+                "build_fn" => expect_list(&key, meta).map(|list| {
+                    self.build_fn = BuildFnOptions::new(list, errors);
+                }),
+                "name" => expect_str(&key, meta).map(|name| self.name = Some(name)),
+                "vis" => expect_str(&key, meta).map(|vis| self.vis = vis),
+                "doc" => expect_lit_str(&key, meta).map(|lit| self.doc = Some(lit)),
+                "derive" => expect_list(&key, meta).map(|list| {
+                    for nested in &list.nested {
+                        match nested {
+                            NestedMeta::Meta(Meta::Path(path)) => self.derive.push(path.clone()),
+                            nested => errors.push(SynError::new_spanned(
+                                nested,
+                                "expected the path of a derive macro",
+                            )),
+                        }
+                    }
+                }),
+                "constructor" => expect_list(&key, meta).map(|list| {
+                    self.constructor = ConstructorOptions::new(list, errors);
+                }),
+                key if FIELD_KEYS.contains(&key) => Err(SynError::new_spanned(
+                    meta.path(),
+                    format!("`{}` can only be used on fields", key),
+                )),
+                key => Err(unknown_key(meta, key, "builder attribute")),
+            };
+            errors.check(result);
+        }
+
+        if let Some(lit) = &pattern_lit
+            && self.typestate
+            && self.pattern != Pattern::Owned
+        {
+            errors.push(SynError::new_spanned(
+                lit,
+                format!(
+                    "{} builders always use the owned pattern",
+                    self.typestate_name()
+                ),
+            ));
+        }
+        if let Some(meta) = getters
+            && self.typestate
+        {
+            errors.push(SynError::new_spanned(
+                meta,
+                format!(
+                    "{} builders do not support `getters`",
+                    self.typestate_name()
+                ),
+            ));
+        }
+        if let Some(meta) = serde
+            && self.typestate
+        {
+            errors.push(SynError::new_spanned(
+                meta,
+                format!("{} builders do not support `serde`", self.typestate_name()),
+            ));
+        }
+
+        // Const builders are owned so that setters can move the builder
+        // through a const context.
+        if let Some(meta) = constant {
+            if let Some(lit) = &pattern_lit
+                && self.pattern != Pattern::Owned
+            {
+                errors.push(SynError::new_spanned(
+                    lit,
+                    "const builders always use the owned pattern",
+                ));
+            }
+            let conflict = if self.typestate {
+                Some(format!(
+                    "{} builders do not support `const`",
+                    self.typestate_name(),
+                ))
+            } else if self.default {
+                Some("const builders do not support `default` on the struct".to_owned())
+            } else if self.build_fn.validate.is_some() {
+                Some("const builders do not support `build_fn(validate)`".to_owned())
+            } else {
+                None
+            };
+            if let Some(message) = conflict {
+                errors.push(SynError::new_spanned(meta, message));
+            }
+        }
+        if self.constant {
+            self.pattern = Pattern::Owned;
+        }
+    }
+
+    fn build_fn_ident(&self) -> Ident {
+        match &self.build_fn.name {
+            Some(name) => name.clone(),
+            None => format_ident!("build"),
+        }
+    }
+
+    // The methods generated on the builder regardless of its fields, which no
+    // method of a field may be named after. `to_builder` is on the target but
+    // kept apart from setters all the same.
+    fn builder_method_names(&self, is_variant: bool) -> Vec<Ident> {
+        let build_fn = self.build_fn_ident();
+        let mut names = vec![format_ident!("clear")];
+        if !is_variant {
+            names.push(format_ident!("to_builder"));
+        }
+        if self.constant {
+            names.push(format_ident!("{}_const", build_fn));
+        }
+        if self.getters {
+            names.push(format_ident!("is_complete"));
+            names.push(format_ident!("missing_fields"));
+        }
+        if self.serde {
+            names.push(format_ident!("merge"));
+            names.push(format_ident!("__merge"));
+        }
+        if !self.typestate && !is_variant {
+            names.push(format_ident!("__edit"));
+            names.push(format_ident!("__build_owned"));
+            names.push(format_ident!("__build_ref"));
+        }
+        if !self.typestate && (!is_variant || self.getters) {
+            names.push(format_ident!("__missing_fields"));
+        }
+        names.push(build_fn);
+        names
+    }
+
+    // How conflict errors refer to a typestate builder, which may have been
+    // asked for through `required_ctor` rather than `typestate` itself.
+    fn typestate_name(&self) -> &'static str {
+        match self.required_ctor {
+            true => "`required_ctor`",
+            false => "typestate",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Pattern {
+    #[default]
+    Mutable,
+    Owned,
+    Immutable,
+}
+
+impl Pattern {
+    fn receiver(self) -> TokenStream2 {
+        match self {
+            Self::Mutable => quote! { &mut self },
+            Self::Owned => quote! { self },
+            Self::Immutable => quote! { &self },
+        }
+    }
+
+    // Only the owned pattern may move out of the builder, the others clone.
+    fn take(self, ident: &Ident) -> TokenStream2 {
+        match self {
+            Self::Owned => quote! { self.#ident },
+            _ => quote! { self.#ident.clone() },
+        }
+    }
+
+    // `attrs` are the outer attributes of the setter, starting with its docs.
+    fn setter<N, F>(
+        self,
+        roots: &Roots,
+        attrs: TokenStream2,
+        name: N,
+        arg: TokenStream2,
+        assign: F,
+    ) -> TokenStream2
+    where
+        N: ToTokens,
+        F: FnOnce(TokenStream2) -> TokenStream2,
+    {
+        let core = &roots.core;
+        match self {
+            Self::Mutable => {
+                let assign = assign(quote! { self });
+                quote! {
+                    #attrs
+                    pub fn #name(&mut self, #arg) -> &mut Self {
+                        #assign
+                        self
+                    }
+                }
+            }
+            Self::Owned => {
+                let assign = assign(quote! { self });
+                quote! {
+                    #attrs
+                    pub fn #name(mut self, #arg) -> Self {
+                        #assign
+                        self
+                    }
+                }
+            }
+            Self::Immutable => {
+                let assign = assign(quote! { __builder });
+                quote! {
+                    #attrs
+                    pub fn #name(&self, #arg) -> Self {
+                        let mut __builder = #core::clone::Clone::clone(self);
+                        #assign
+                        __builder
+                    }
+                }
+            }
+        }
+    }
+
+    // A setter converting its argument with `TryInto` first, which returns the
+    // conversion error instead of the builder when that fails.
+    fn try_setter<F>(
+        self,
+        roots: &Roots,
+        attrs: TokenStream2,
+        name: &Ident,
+        ident: &Ident,
+        ty: &Type,
+        assign: F,
+    ) -> TokenStream2
+    where
+        F: FnOnce(TokenStream2) -> TokenStream2,
+    {
+        let core = &roots.core;
+        let name = format_ident!("try_{}", name);
+        let bound = quote! { __U: #core::convert::TryInto<#ty> };
+        let error = quote! { <__U as #core::convert::TryInto<#ty>>::Error };
+        let convert = quote! {
+            let #ident = #core::convert::TryInto::try_into(#ident)?;
+        };
+        match self {
+            Self::Mutable => {
+                let assign = assign(quote! { self });
+                quote! {
+                    #attrs
+                    pub fn #name<#bound>(
+                        &mut self,
+                        #ident: __U,
+                    ) -> #core::result::Result<&mut Self, #error> {
+                        #convert
+                        #assign
+                        #core::result::Result::Ok(self)
+                    }
+                }
+            }
+            Self::Owned => {
+                let assign = assign(quote! { self });
+                quote! {
+                    #attrs
+                    pub fn #name<#bound>(
+                        mut self,
+                        #ident: __U,
+                    ) -> #core::result::Result<Self, #error> {
+                        #convert
+                        #assign
+                        #core::result::Result::Ok(self)
+                    }
+                }
+            }
+            Self::Immutable => {
+                let assign = assign(quote! { __builder });
+                quote! {
+                    #attrs
+                    pub fn #name<#bound>(&self, #ident: __U) -> #core::result::Result<Self, #error> {
+                        #convert
+                        let mut __builder = #core::clone::Clone::clone(self);
+                        #assign
+                        #core::result::Result::Ok(__builder)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct BuildFnOptions {
+    validate: Option<Path>,
+    name: Option<Ident>,
+}
+
+impl BuildFnOptions {
+    fn new(list: &MetaList, errors: &mut Errors) -> Self {
+        let mut options = Self::default();
+        for (key, meta) in builder_keys(&list.nested, "build_fn option", errors) {
+            let result = match key.as_str() {
+                "validate" => expect_str(&key, meta).map(|path| options.validate = Some(path)),
+                "name" => expect_str(&key, meta).map(|name| options.name = Some(name)),
+                key => Err(unknown_key(meta, key, "build_fn option")),
+            };
+            errors.check(result);
+        }
+        options
+    }
+}
+
+#[derive(Default)]
+struct ConstructorOptions {
+    name: Option<Ident>,
+}
+
+impl ConstructorOptions {
+    fn new(list: &MetaList, errors: &mut Errors) -> Self {
+        let mut options = Self::default();
+        for (key, meta) in builder_keys(&list.nested, "constructor option", errors) {
+            let result = match key.as_str() {
+                "name" => expect_str(&key, meta).map(|name| options.name = Some(name)),
+                key => Err(unknown_key(meta, key, "constructor option")),
+            };
+            errors.check(result);
+        }
+        options
+    }
+}
+
+#[derive(Clone, Default)]
+struct SetterOptions {
+    into: bool,
+    strip_option: bool,
+    custom: bool,
+    name: Option<Ident>,
+}
+
+impl SetterOptions {
+    fn new(list: &MetaList, errors: &mut Errors) -> Self {
+        let mut options = Self::default();
+        for (key, meta) in builder_keys(&list.nested, "setter option", errors) {
+            let result = match key.as_str() {
+                "into" => expect_flag(&key, meta).map(|()| options.into = true),
+                "strip_option" => expect_flag(&key, meta).map(|()| options.strip_option = true),
+                "custom" => expect_flag(&key, meta).map(|()| options.custom = true),
+                "name" => expect_str(&key, meta).map(|name| options.name = Some(name)),
+                key => Err(unknown_key(meta, key, "setter option")),
+            };
+            errors.check(result);
+        }
+        options
+    }
+
+    fn merge(self, other: &Self) -> Self {
+        Self {
+            into: self.into || other.into,
+            strip_option: self.strip_option || other.strip_option,
+            custom: self.custom || other.custom,
+            name: self.name.or_else(|| other.name.clone()),
+        }
+    }
+
+    // Returns the setter's parameter declaration and the expression turning
+    // that parameter into a value of the field type.
+    fn arg<N: ToTokens>(&self, roots: &Roots, name: N, ty: &Type) -> (TokenStream2, TokenStream2) {
+        let core = &roots.core;
+        if self.into {
+            (
+                quote! { #name: impl #core::convert::Into<#ty> },
+                quote! { #core::convert::Into::into(#name) },
+            )
+        } else {
+            (quote! { #name: #ty }, quote! { #name })
+        }
+    }
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    each: Option<Ident>,
+    default: Option<DefaultValue>,
+    setter: SetterOptions,
+    validate: Option<Path>,
+    name: Option<Ident>,
+    optional: bool,
+    required: bool,
+    getters: Option<Path>,
+    skip: bool,
+    compute: Option<Box<Expr>>,
+    try_setter: bool,
+    sub_builder: Option<Path>,
+    field_attrs: Vec<Meta>,
+    setter_attrs: Vec<Meta>,
+}
+
+impl FieldOptions {
+    fn new(attrs: &[Attribute], errors: &mut Errors) -> Self {
+        let mut options = Self::default();
+        let metas = builder_metas(attrs, errors);
+        let mut required = None;
+        let mut compute = None;
+        // Keys that configure a setter, which computed fields do not have.
+        let mut setter_keys = Vec::new();
+        for (key, meta) in builder_keys(&metas, "builder attribute", errors) {
+            if matches!(key.as_str(), "setter" | "try_setter" | "validate") {
+                setter_keys.push((key.clone(), meta));
+            }
+            let result = match (key.as_str(), meta) {
+                ("each", meta) => expect_str(&key, meta).map(|each| options.each = Some(each)),
+                ("default", Meta::Path(_)) => {
+                    options.default = Some(DefaultValue::Trait);
+                    Ok(())
+                }
+                ("default", Meta::NameValue(nv)) => parse_lit_str(&nv.lit).map(|expr| {
+                    options.default = Some(DefaultValue::Expr(Box::new(expr)));
+                }),
+                ("default", meta) => Err(SynError::new_spanned(
+                    meta,
+                    "expected `default` or `default = \"...\"`",
+                )),
+                ("setter", meta) => expect_list(&key, meta).map(|list| {
+                    options.setter = SetterOptions::new(list, errors);
+                }),
+                ("validate", meta) => {
+                    expect_str(&key, meta).map(|path| options.validate = Some(path))
+                }
+                ("name", meta) => expect_str(&key, meta).map(|name| options.name = Some(name)),
+                ("optional", meta) => expect_flag(&key, meta).map(|()| options.optional = true),
+                ("skip", meta) => expect_flag(&key, meta).map(|()| options.skip = true),
+                ("try_setter", meta) => expect_flag(&key, meta).map(|()| options.try_setter = true),
+                ("compute", meta) => expect_str(&key, meta).map(|expr| {
+                    options.compute = Some(Box::new(expr));
+                    compute = Some(meta);
+                }),
+                ("getters", meta) => expect_flag(&key, meta).map(|()| {
+                    options.getters = Some(meta.path().clone());
+                }),
+                ("sub_builder", meta) => expect_flag(&key, meta).map(|()| {
+                    options.sub_builder = Some(meta.path().clone());
+                }),
+                ("field_attr", meta) => expect_list(&key, meta).map(|list| {
+                    options.field_attrs = attribute_metas(list, errors);
+                }),
+                ("setter_attr", meta) => expect_list(&key, meta).map(|list| {
+                    options.setter_attrs = attribute_metas(list, errors);
+                }),
+                ("required", meta) => expect_flag(&key, meta).map(|()| {
+                    options.required = true;
+                    required = Some(meta);
+                }),
+                (key, meta) if STRUCT_KEYS.contains(&key) => Err(SynError::new_spanned(
+                    meta.path(),
+                    format!("`{}` cannot be used on a field", key),
+                )),
+                (key, meta) => Err(unknown_key(meta, key, "builder attribute")),
+            };
+            errors.check(result);
+        }
+        if let Some(meta) = compute
+            && (options.skip || options.default.is_some())
+        {
+            errors.push(SynError::new_spanned(
+                meta,
+                "`compute` conflicts with `skip` and `default`",
+            ));
+        }
+        if options.optional
+            && let Some(meta) = required
+        {
+            errors.push(SynError::new_spanned(
+                meta,
+                "`required` conflicts with `optional`",
+            ));
+        }
+        if options.each.is_some()
+            && let Some(meta) = required
+        {
+            errors.push(SynError::new_spanned(
+                meta,
+                "`required` conflicts with `each`",
+            ));
+        }
+        if options.skip || options.compute.is_some() {
+            for (key, meta) in setter_keys {
+                errors.push(SynError::new_spanned(
+                    meta.path(),
+                    format!("`{}` conflicts with `skip` and `compute`", key),
+                ));
+            }
+        }
+        if let Some(path) = &options.sub_builder
+            && (options.each.is_some()
+                || options.default.is_some()
+                || options.optional
+                || options.skip
+                || options.compute.is_some())
+        {
+            errors.push(SynError::new_spanned(
+                path,
+                "`sub_builder` conflicts with `each`, `default`, `optional`, `skip` and `compute`",
+            ));
+        }
+        options
+    }
+}
+
+#[derive(Clone)]
+enum DefaultValue {
+    Trait,
+    Expr(Box<Expr>),
+    Struct(Member),
+}
+
+impl DefaultValue {
+    fn to_expr(&self, roots: &Roots) -> TokenStream2 {
+        let core = &roots.core;
+        match self {
+            Self::Trait => quote! { #core::default::Default::default() },
+            Self::Expr(expr) => quote! { #expr },
+            Self::Struct(member) => quote! { __default.#member },
+        }
+    }
+}
+
+enum FieldType<'a> {
+    Normal {
+        ident: Ident,
+        ty: Cow<'a, Type>,
+    },
+    Default {
+        ident: Ident,
+        ty: Cow<'a, Type>,
+        default: DefaultValue,
+    },
+    Option {
+        ident: Ident,
+        ty: Cow<'a, Type>,
+        default: Option<DefaultValue>,
+    },
+    Each {
+        ident: Ident,
+        ty: Cow<'a, Type>,
+        item: EachItem<'a>,
+        each: Ident,
+        default: Option<DefaultValue>,
+    },
+    // Fields whose type derives `Builder` themselves, built along with the
+    // outer builder.
+    SubBuilder {
+        ident: Ident,
+        builder: Type,
+        error: Box<Type>,
+        edit: Box<Type>,
+    },
+    // Fields without a setter, set by `build` alone.
+    Computed {
+        ident: Ident,
+        value: DefaultValue,
+    },
+}
+
+// What a single call to an `each` setter adds to the collection. Maps take
+// the key and value as separate arguments.
+enum EachItem<'a> {
+    Value(Box<Type>),
+    Entry(&'a Type, &'a Type),
+}
+
+impl<'a> EachItem<'a> {
+    fn new(ty: &'a Type, roots: &Roots) -> Self {
+        for path in [
+            VEC,
+            VEC_DEQUE,
+            LINKED_LIST,
+            HASH_SET,
+            BTREE_SET,
+            BINARY_HEAP,
+        ] {
+            if let Some([item]) = std_generic_args(ty, path).as_deref() {
+                return Self::Value(Box::new((*item).clone()));
+            }
+        }
+        for path in [HASH_MAP, BTREE_MAP] {
+            if let Some([key, value, ..]) = std_generic_args(ty, path).as_deref() {
+                return Self::Entry(key, value);
+            }
+        }
+        // Any other `Default + Extend` collection, which is expected to
+        // extend with the same items it yields.
+        let core = roots.core_at(ty.span());
+        Self::Value(Box::new(parse_quote_spanned! {ty.span()=>
+            <#ty as #core::iter::IntoIterator>::Item
+        }))
+    }
+
+    fn ty(&self) -> TokenStream2 {
+        match self {
+            Self::Value(ty) => quote! { #ty },
+            Self::Entry(key, value) => quote! { (#key, #value) },
+        }
+    }
+}
+
+impl<'a> FieldType<'a> {
+    fn new(
+        field: &'a Field,
+        ident: Ident,
+        member: &Member,
+        options: &FieldOptions,
+        struct_default: bool,
+        roots: &Roots,
+    ) -> Self {
+        let ty = &field.ty;
+        let default = options
+            .default
+            .clone()
+            .or_else(|| struct_default.then(|| DefaultValue::Struct(member.clone())));
+
+        if let Some(compute) = &options.compute {
+            let value = DefaultValue::Expr(compute.clone());
+            return Self::Computed { ident, value };
+        }
+        if options.skip {
+            let value = default.unwrap_or(DefaultValue::Trait);
+            return Self::Computed { ident, value };
+        }
+        if options.sub_builder.is_some()
+            && let Some((builder, error, edit)) = sub_builder_types(ty)
+        {
+            return Self::SubBuilder {
+                ident,
+                builder,
+                error: Box::new(error),
+                edit: Box::new(edit),
+            };
+        }
+
+        if !options.required {
+            if let Some(ty) = std_generic_arg(ty, OPTION) {
+                let ty = Cow::Borrowed(ty);
+                return Self::Option { ident, ty, default };
+            }
+            // The field is an alias of `Option<T>`, which yields `T` when
+            // iterated over.
+            if options.optional {
+                let core = roots.core_at(ty.span());
+                let ty = Cow::Owned(parse_quote_spanned! {ty.span()=>
+                    <#ty as #core::iter::IntoIterator>::Item
+                });
+                return Self::Option { ident, ty, default };
+            }
+        }
+
+        if let Some(each) = &options.each {
+            return Self::Each {
+                ident,
+                ty: Cow::Borrowed(ty),
+                item: EachItem::new(ty, roots),
+                each: each.clone(),
+                default,
+            };
+        }
+
+        let ty = Cow::Borrowed(ty);
+        match default {
+            Some(default) => Self::Default { ident, ty, default },
+            None => Self::Normal { ident, ty },
+        }
+    }
+
+    // The value of the field's slot in an empty builder.
+    fn init(&self, roots: &Roots) -> TokenStream2 {
+        let core = &roots.core;
+        match self {
+            Self::Each { .. } | Self::SubBuilder { .. } => {
+                quote! { #core::default::Default::default() }
+            }
+            _ => quote! { #core::option::Option::None },
+        }
+    }
+
+    fn ident(&self) -> &Ident {
+        match self {
+            Self::Normal { ident, .. } => ident,
+            Self::Default { ident, .. } => ident,
+            Self::Option { ident, .. } => ident,
+            Self::Each { ident, .. } => ident,
+            Self::SubBuilder { ident, .. } => ident,
+            Self::Computed { ident, .. } => ident,
+        }
+    }
+}
+
+// Fields of tuple structs are named `_0`, `_1`, ... in the builder unless a
+// name is given with `builder(name = "...")`.
+struct BuilderField<'a> {
+    member: Member,
+    kind: FieldType<'a>,
+    options: FieldOptions,
+    docs: Vec<&'a Attribute>,
+    constant: bool,
+    roots: Roots,
+}
+
+impl<'a> BuilderField<'a> {
+    fn new(
+        field: &'a Field,
+        index: usize,
+        struct_options: &StructOptions,
+        errors: &mut Errors,
+    ) -> Self {
+        let mut options = FieldOptions::new(&field.attrs, errors);
+        // Only `strip_option` given on the field itself has to apply to it.
+        let strip_option = options.setter.strip_option;
+        options.setter = options.setter.merge(&struct_options.setter);
+        options.try_setter |= struct_options.try_setter;
+        if let Some(path) = &options.getters
+            && struct_options.typestate
+        {
+            errors.push(SynError::new_spanned(
+                path,
+                format!(
+                    "{} builders do not support `getters`",
+                    struct_options.typestate_name(),
+                ),
+            ));
+        }
+        if let Some(path) = &options.sub_builder {
+            if struct_options.typestate {
+                errors.push(SynError::new_spanned(
+                    path,
+                    format!(
+                        "{} builders do not support `sub_builder`",
+                        struct_options.typestate_name(),
+                    ),
+                ));
+            }
+            if sub_builder_types(&field.ty).is_none() {
+                errors.push(SynError::new_spanned(
+                    &field.ty,
+                    "`sub_builder` expects the path of a type deriving `Builder`",
+                ));
+            }
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let ident = match (&options.name, &field.ident) {
+            (Some(name), _) => name.clone(),
+            (None, Some(ident)) => ident.clone(),
+            (None, None) => format_ident!("_{}", index),
+        };
+        let roots = Roots::new(struct_options.no_std);
+        let kind = FieldType::new(
+            field,
+            ident,
+            &member,
+            &options,
+            struct_options.default,
+            &roots,
+        );
+        let is_option = matches!(kind, FieldType::Option { .. });
+        if let Some(each) = &options.each
+            && is_option
+        {
+            errors.push(SynError::new_spanned(
+                each,
+                "`each` cannot be used on an `Option` field",
+            ));
+        }
+        if strip_option && !is_option && !matches!(kind, FieldType::Computed { .. }) {
+            errors.push(SynError::new_spanned(
+                &field.ty,
+                "`strip_option` expects a field of type `Option<T>`",
+            ));
+        }
+        // `build_const` and the setters have to be evaluable in a const fn.
+        if struct_options.constant {
+            let conflict = match &kind {
+                FieldType::Each { .. } => Some("`each`"),
+                FieldType::SubBuilder { .. } => Some("`sub_builder`"),
+                FieldType::Default {
+                    default: DefaultValue::Trait,
+                    ..
+                }
+                | FieldType::Option {
+                    default: Some(DefaultValue::Trait),
+                    ..
+                }
+                | FieldType::Computed {
+                    value: DefaultValue::Trait,
+                    ..
+                } => Some("defaults without an expression"),
+                _ if options.validate.is_some() => Some("`validate`"),
+                _ if options.setter.into => Some("`setter(into)`"),
+                _ => None,
+            };
+            if let Some(option) = conflict {
+                errors.push(SynError::new_spanned(
+                    field,
+                    format!("const builders do not support {}", option),
+                ));
+            }
+        }
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .collect();
+        Self {
+            member,
+            kind,
+            options,
+            docs,
+            constant: struct_options.constant,
+            roots,
+        }
+    }
+
+    fn ident(&self) -> &Ident {
+        self.kind.ident()
+    }
+
+    fn setter_name(&self) -> Ident {
+        match &self.options.setter.name {
+            Some(name) => name.clone(),
+            None => self.ident().clone(),
+        }
+    }
+
+    // Names of all setters generated for the field.
+    fn setter_names(&self) -> Vec<Ident> {
+        let name = self.setter_name();
+        let mut names = self.default_setter_names(name.clone());
+        if self.options.setter.custom {
+            names.clear();
+        }
+        if self.options.try_setter && !self.is_computed() && !self.is_sub_builder() {
+            names.push(format_ident!("try_{}", name));
+        }
+        names
+    }
+
+    // Names of every method generated for the field: its setters, its reset
+    // and its getter.
+    fn method_names(&self, struct_options: &StructOptions) -> Vec<Ident> {
+        let mut names = self.setter_names();
+        names.extend(self.reset_name(struct_options.typestate));
+        names.extend(self.getter_name(struct_options.getters));
+        names
+    }
+
+    // Required fields of a typestate builder cannot be unset one by one, as
+    // that changes the type of the builder.
+    fn reset_name(&self, typestate: bool) -> Option<Ident> {
+        match &self.kind {
+            FieldType::Computed { .. } => None,
+            FieldType::Normal { .. } if typestate => None,
+            _ => Some(self.derived_name("reset")),
+        }
+    }
+
+    // Name of a method derived from the name of the setter, such as
+    // `reset_<setter>`. Unnamed fields of tuple structs give `reset_0` rather
+    // than `reset__0`.
+    fn derived_name(&self, prefix: &str) -> Ident {
+        let name = self.setter_name();
+        match (&self.member, &self.options.name, &self.options.setter.name) {
+            (Member::Unnamed(index), None, None) => {
+                format_ident!("{}_{}", prefix, index.index, span = name.span())
+            }
+            _ => format_ident!("{}_{}", prefix, name),
+        }
+    }
+
+    fn getter_name(&self, struct_getters: bool) -> Option<Ident> {
+        if self.is_computed() || !struct_getters && self.options.getters.is_none() {
+            return None;
+        }
+        Some(format_ident!("{}_ref", self.ident()))
+    }
+
+    fn default_setter_names(&self, name: Ident) -> Vec<Ident> {
+        match &self.kind {
+            FieldType::Option { .. } if self.options.setter.strip_option => {
+                let opt = format_ident!("{}_opt", name);
+                vec![name, opt]
+            }
+            FieldType::Each { each, .. } => {
+                let extend = self.derived_name("extend");
+                if *each == name {
+                    vec![each.clone(), extend]
+                } else {
+                    vec![name, each.clone(), extend]
+                }
+            }
+            FieldType::SubBuilder { .. } => {
+                let name_mut = format_ident!("{}_mut", name);
+                vec![name, name_mut]
+            }
+            FieldType::Computed { .. } => vec![],
+            _ => vec![name],
+        }
+    }
+
+    fn is_computed(&self) -> bool {
+        matches!(self.kind, FieldType::Computed { .. })
+    }
+
+    fn is_sub_builder(&self) -> bool {
+        matches!(self.kind, FieldType::SubBuilder { .. })
+    }
+
+    // The docs of a setter followed by the attributes given with
+    // `builder(setter_attr(...))`.
+    fn gen_setter_attrs(&self, doc: TokenStream2) -> TokenStream2 {
+        let attrs = &self.options.setter_attrs;
+        quote! {
+            #doc
+            #(#[#attrs])*
+        }
+    }
+
+    // The field's docs followed by the attributes given with
+    // `builder(field_attr(...))`, for the field of the builder.
+    fn gen_field_attrs(&self) -> TokenStream2 {
+        let docs = &self.docs;
+        let attrs = &self.options.field_attrs;
+        quote! {
+            #(#docs)*
+            #(#[#attrs])*
+        }
+    }
+
+    // Docs of the main setter of the field: the summary, defaulting to the
+    // field's own docs, followed by whether `build` needs the field to be set.
+    fn gen_docs(&self, summary: Option<String>) -> TokenStream2 {
+        let ident = self.ident();
+        let summary = match summary {
+            Some(summary) => doc(&summary),
+            None if !self.docs.is_empty() => quote! {},
+            None => doc(&format!("Sets `{}`.", ident)),
+        };
+        let docs = &self.docs;
+        let note = match &self.kind {
+            FieldType::Normal { .. } => "This field is required.",
+            FieldType::Default { .. }
+            | FieldType::Option {
+                default: Some(_), ..
+            }
+            | FieldType::Each {
+                default: Some(_), ..
+            } => "This field is optional, a default is used when it is not set.",
+            FieldType::Option { .. } | FieldType::Each { .. } => "This field is optional.",
+            FieldType::SubBuilder { .. } => {
+                "This field is built by its own builder, whose errors are returned by `build`."
+            }
+            FieldType::Computed { .. } => return quote! {},
+        };
+        let note = doc(&format!("\n{}", note));
+        quote! {
+            #summary
+            #(#docs)*
+            #note
+        }
+    }
+
+    fn gen_reset(&self, pattern: Pattern, typestate: bool) -> TokenStream2 {
+        let Some(name) = self.reset_name(typestate) else {
+            return quote! {};
+        };
+        let ident = self.ident();
+        let init = self.kind.init(&self.roots);
+        let doc = doc(&format!("Unsets `{}`, as in a new builder.", ident));
+        pattern.setter(&self.roots, doc, name, quote! {}, |this| {
+            quote! {
+                #this.#ident = #init;
+            }
+        })
+    }
+
+    // Setters that store into an `Option` or `Vec` slot of the builder, which
+    // is every field except the required ones of a typestate builder.
+    fn gen_setters(&self, pattern: Pattern) -> TokenStream2 {
+        let core = &self.roots.core;
+        let setters = (!self.options.setter.custom).then(|| self.gen_default_setters(pattern));
+        let try_setter = self.options.try_setter.then(|| {
+            let name = self.setter_name();
+            match &self.kind {
+                FieldType::Normal { ident, ty }
+                | FieldType::Default { ident, ty, .. }
+                | FieldType::Option { ident, ty, .. } => {
+                    let attrs = self.gen_setter_attrs(try_setter_doc(ident));
+                    pattern.try_setter(&self.roots, attrs, &name, ident, ty, |this| {
+                        quote! {
+                            #this.#ident = #core::option::Option::Some(#ident);
+                        }
+                    })
+                }
+                FieldType::Each { ident, ty, .. } => {
+                    let attrs = self.gen_setter_attrs(try_setter_doc(ident));
+                    pattern.try_setter(&self.roots, attrs, &name, ident, ty, |this| {
+                        quote! {
+                            #this.#ident = #ident;
+                        }
+                    })
+                }
+                FieldType::SubBuilder { .. } | FieldType::Computed { .. } => quote! {},
+            }
+        });
+        quote! {
+            #setters
+            #try_setter
+        }
+    }
+
+    // The setters storing a single value are `const fn`s in const builders,
+    // which always use the owned pattern.
+    fn gen_value_setter<N, F>(
+        &self,
+        pattern: Pattern,
+        attrs: TokenStream2,
+        name: N,
+        arg: TokenStream2,
+        assign: F,
+    ) -> TokenStream2
+    where
+        N: ToTokens,
+        F: FnOnce(TokenStream2) -> TokenStream2,
+    {
+        if !self.constant {
+            return pattern.setter(&self.roots, attrs, name, arg, assign);
+        }
+        let assign = assign(quote! { self });
+        quote! {
+            #attrs
+            pub const fn #name(mut self, #arg) -> Self {
+                #assign
+                self
+            }
+        }
+    }
+
+    fn gen_default_setters(&self, pattern: Pattern) -> TokenStream2 {
+        let core = &self.roots.core;
+        let setter = &self.options.setter;
+        let name = self.setter_name();
+        match &self.kind {
+            FieldType::Normal { ident, ty } | FieldType::Default { ident, ty, .. } => {
+                let (arg, value) = setter.arg(&self.roots, ident, ty);
+                self.gen_value_setter(
+                    pattern,
+                    self.gen_setter_attrs(self.gen_docs(None)),
+                    name,
+                    arg,
+                    |this| {
+                        quote! {
+                            #this.#ident = #core::option::Option::Some(#value);
+                        }
+                    },
+                )
+            }
+            FieldType::Option { ident, ty, .. } => {
+                let (arg, value) = setter.arg(&self.roots, ident, ty);
+                let attrs = self.gen_setter_attrs(self.gen_docs(None));
+                let strip = self.gen_value_setter(pattern, attrs, &name, arg, |this| {
+                    quote! {
+                        #this.#ident = #core::option::Option::Some(#value);
+                    }
+                });
+                let opt = setter.strip_option.then(|| {
+                    let name = format_ident!("{}_opt", name);
+                    let doc = doc(&format!(
+                        "Sets `{}` to an `Option`, `None` unsets it.",
+                        ident
+                    ));
+                    let arg = quote! { #ident: #core::option::Option<#ty> };
+                    let attrs = self.gen_setter_attrs(doc);
+                    self.gen_value_setter(pattern, attrs, name, arg, |this| {
+                        quote! {
+                            #this.#ident = #ident;
+                        }
+                    })
+                });
+                quote! {
+                    #strip
+                    #opt
+                }
+            }
+            FieldType::Each {
+                ident,
+                ty,
+                item,
+                each,
+                ..
+            } => {
+                // A setter named like the `each` one would clash with it, so
+                // only the latter is kept.
+                let whole = (*each != name).then(|| {
+                    let (arg, value) = setter.arg(&self.roots, ident, ty);
+                    let attrs = self.gen_setter_attrs(self.gen_docs(None));
+                    pattern.setter(&self.roots, attrs, &name, arg, |this| {
+                        quote! {
+                            #this.#ident = #value;
+                        }
+                    })
+                });
+
+                let (arg, value) = match item {
+                    EachItem::Value(ty) => setter.arg(&self.roots, each, ty),
+                    EachItem::Entry(key, value) => {
+                        let (key_arg, key) = setter.arg(&self.roots, quote! { key }, key);
+                        let (value_arg, value) = setter.arg(&self.roots, quote! { value }, value);
+                        (quote! { #key_arg, #value_arg }, quote! { (#key, #value) })
+                    }
+                };
+                // Without the whole setter, the field is documented here.
+                let summary = format!("Adds an item to `{}`.", ident);
+                let each_doc = match whole {
+                    Some(_) => doc(&summary),
+                    None => self.gen_docs(Some(summary)),
+                };
+                let each_attrs = self.gen_setter_attrs(each_doc);
+                let each = pattern.setter(&self.roots, each_attrs, each, arg, |this| {
+                    quote! {
+                        #core::iter::Extend::extend(&mut #this.#ident, #core::iter::once(#value));
+                    }
+                });
+
+                let name = self.derived_name("extend");
+                let item = item.ty();
+                let arg = quote! { #ident: impl #core::iter::IntoIterator<Item = #item> };
+                let doc = doc(&format!("Adds every item of an iterator to `{}`.", ident));
+                let extend =
+                    pattern.setter(&self.roots, self.gen_setter_attrs(doc), name, arg, |this| {
+                        quote! {
+                            #core::iter::Extend::extend(&mut #this.#ident, #ident);
+                        }
+                    });
+                // Both setters only fail to compile when the field is not a
+                // collection, which is then reported at its type.
+                let each = respan(each, ty.span());
+                let extend = respan(extend, ty.span());
+                quote! {
+                    #whole
+                    #each
+                    #extend
+                }
+            }
+            // The sub builder is edited in place, either through a mutable
+            // reference or a closure taking it the way its own setters do.
+            FieldType::SubBuilder {
+                ident,
+                builder,
+                edit,
+                ..
+            } => {
+                let name_mut = format_ident!("{}_mut", name);
+                let arg = quote! { f: impl #edit };
+                let setter_doc = self.gen_docs(Some(format!("Edits the builder of `{}`.", ident)));
+                let setter = pattern.setter(
+                    &self.roots,
+                    self.gen_setter_attrs(setter_doc),
+                    name,
+                    arg,
+                    |this| {
+                        quote! {
+                            #this.#ident.__edit(f);
+                        }
+                    },
+                );
+                let attrs_mut =
+                    self.gen_setter_attrs(doc(&format!("Returns the builder of `{}`.", ident)));
+                quote! {
+                    #setter
+
+                    #attrs_mut
+                    pub fn #name_mut(&mut self) -> &mut #builder {
+                        &mut self.#ident
+                    }
+                }
+            }
+            FieldType::Computed { .. } => quote! {},
+        }
+    }
+}
+
+fn fields_map<F>(fields: &[BuilderField], f: F) -> TokenStream2
+where
+    F: FnMut(&BuilderField) -> TokenStream2,
+{
+    fields.iter().flat_map(f).collect()
+}
+
+// `#[doc]` attributes of a generated item, one per line of `text`.
+fn doc(text: &str) -> TokenStream2 {
+    text.split('\n')
+        .map(|line| {
+            let line = if line.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", line)
+            };
+            quote! {
+                #[doc = #line]
+            }
+        })
+        .collect()
+}
+
+fn try_setter_doc(ident: &Ident) -> TokenStream2 {
+    doc(&format!(
+        "Sets `{}` from a value converted with `TryInto`, failing with the conversion error.",
+        ident,
+    ))
+}
+
+// The crates the generated code names the standard library through, `core`
+// and `alloc` with `builder(no_std)` or `std` for both otherwise. Only the
+// macro's own paths go through these, the user's tokens are left as written.
+struct Roots {
+    core: Ident,
+    alloc: Ident,
+}
+
+impl Roots {
+    fn new(no_std: bool) -> Self {
+        match no_std {
+            true => Self {
+                core: format_ident!("core"),
+                alloc: format_ident!("alloc"),
+            },
+            false => Self {
+                core: format_ident!("std"),
+                alloc: format_ident!("std"),
+            },
+        }
+    }
+
+    // `core` with the span of user tokens, so that an error in a path starting
+    // with it points at them.
+    fn core_at(&self, span: Span) -> Ident {
+        let mut core = self.core.clone();
+        core.set_span(span);
+        core
+    }
+}
+
+// Gives every token the span of `span`, so that errors in the generated code
+// point at the user tokens at fault.
+fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                TokenTree::Group(respanned)
+            }
+            mut token => {
+                token.set_span(span);
+                token
+            }
+        })
+        .collect()
+}
+
+// Paths of the standard types recognised by the macro, starting with the crate
+// defining them. They may be named through that crate or through `std`.
+const OPTION: &[&str] = &["core", "option", "Option"];
+const VEC: &[&str] = &["alloc", "vec", "Vec"];
+const VEC_DEQUE: &[&str] = &["alloc", "collections", "VecDeque"];
+const LINKED_LIST: &[&str] = &["alloc", "collections", "LinkedList"];
+const HASH_SET: &[&str] = &["std", "collections", "HashSet"];
+const BTREE_SET: &[&str] = &["alloc", "collections", "BTreeSet"];
+const BINARY_HEAP: &[&str] = &["alloc", "collections", "BinaryHeap"];
+const HASH_MAP: &[&str] = &["std", "collections", "HashMap"];
+const BTREE_MAP: &[&str] = &["alloc", "collections", "BTreeMap"];
+
+fn std_generic_arg<'a>(target: &'a Type, path: &[&str]) -> Option<&'a Type> {
+    std_generic_args(target, path)?.first().copied()
+}
+
+// Returns the generic type arguments of `target` if it names the standard
+// type at `path`, either by its last segment alone as brought in by the
+// prelude or a `use`, or by its full path. A type of the user's own used under
+// the same last segment alone cannot be told apart, and is taken for the
+// standard one unless the field is marked `builder(required)`.
+fn std_generic_args<'a>(target: &'a Type, path: &[&str]) -> Option<Vec<&'a Type>> {
+    let Type::Path(TypePath {
+        qself: None,
+        path: target,
+    }) = target
+    else {
+        return None;
+    };
+    let segments: Vec<_> = target.segments.iter().collect();
+    let (last, modules) = segments.split_last()?;
+    let (name, path) = path.split_last()?;
+    let (krate, path) = path.split_first()?;
+    if last.ident != name {
+        return None;
+    }
+    let matches = match modules.split_first() {
+        None => target.leading_colon.is_none(),
+        Some((root, rest)) => {
+            (root.ident == "std" || root.ident == krate)
+                && rest.len() == path.len()
+                && rest.iter().zip(path).all(|(seg, name)| seg.ident == name)
+                && modules.iter().all(|seg| seg.arguments.is_empty())
+        }
+    };
+    if !matches {
+        return None;
+    }
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) => Some(
+            args.args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn parse_lit_str<T: Parse>(lit: &Lit) -> Result<T, SynError> {
+    match lit {
+        Lit::Str(lit) => lit.parse(),
+        lit => Err(SynError::new_spanned(lit, "expected a string literal")),
+    }
+}
+
+fn builder_metas(attrs: &[Attribute], errors: &mut Errors) -> Vec<NestedMeta> {
+    let mut metas = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("builder")) {
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => metas.extend(list.nested),
+            Ok(meta) => errors.push(SynError::new_spanned(meta, "expected `builder(...)`")),
+            Err(err) => errors.push(err),
+        }
+    }
+    metas
+}
+
+// Pairs every item of a `builder(...)` list, or of a nested list such as
+// `setter(...)`, with its key, dropping literals and repeated keys.
+fn builder_keys<'a, I>(nested: I, kind: &str, errors: &mut Errors) -> Vec<(String, &'a Meta)>
+where
+    I: IntoIterator<Item = &'a NestedMeta>,
+{
+    let mut keys: Vec<(String, &Meta)> = Vec::new();
+    for nested in nested {
+        let meta = match nested {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::Lit(lit) => {
+                errors.push(SynError::new_spanned(lit, format!("expected a {}", kind)));
+                continue;
+            }
+        };
+        let Some(key) = meta.path().get_ident().map(Ident::to_string) else {
+            errors.push(SynError::new_spanned(
+                meta.path(),
+                format!("unknown {}", kind),
+            ));
+            continue;
+        };
+        if keys.iter().any(|(seen, _)| *seen == key) {
+            errors.push(SynError::new_spanned(
+                meta.path(),
+                format!("duplicate {} `{}`", kind, key),
+            ));
+            continue;
+        }
+        keys.push((key, meta));
+    }
+    keys
+}
+
+// The attributes listed in `field_attr(...)` or `setter_attr(...)`.
+fn attribute_metas(list: &MetaList, errors: &mut Errors) -> Vec<Meta> {
+    let mut metas = Vec::new();
+    for nested in &list.nested {
+        match nested {
+            NestedMeta::Meta(meta) => metas.push(meta.clone()),
+            NestedMeta::Lit(lit) => {
+                errors.push(SynError::new_spanned(lit, "expected an attribute"));
+            }
+        }
+    }
+    metas
+}
+
+fn unknown_key(meta: &Meta, key: &str, kind: &str) -> SynError {
+    SynError::new_spanned(meta.path(), format!("unknown {} `{}`", kind, key))
+}
+
+fn expect_flag(key: &str, meta: &Meta) -> Result<(), SynError> {
+    match meta {
+        Meta::Path(_) => Ok(()),
+        meta => Err(SynError::new_spanned(meta, format!("expected `{}`", key))),
+    }
+}
+
+fn expect_lit_str(key: &str, meta: &Meta) -> Result<LitStr, SynError> {
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit.clone()),
+        Meta::NameValue(nv) => Err(SynError::new_spanned(&nv.lit, "expected a string literal")),
+        meta => Err(SynError::new_spanned(
+            meta,
+            format!("expected `{} = \"...\"`", key),
+        )),
+    }
+}
+
+fn expect_str<T: Parse>(key: &str, meta: &Meta) -> Result<T, SynError> {
+    expect_lit_str(key, meta)?.parse()
+}
+
+fn expect_list<'a>(key: &str, meta: &'a Meta) -> Result<&'a MetaList, SynError> {
+    match meta {
+        Meta::List(list) => Ok(list),
+        meta => Err(SynError::new_spanned(
+            meta,
+            format!("expected `{}(...)`", key),
+        )),
+    }
+}
+
+// Collects every error found in the attributes so that they are all reported
+// in one compile.
+#[derive(Default)]
+struct Errors(Option<SynError>);
+
+impl Errors {
+    fn push(&mut self, err: SynError) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(err),
+            None => self.0 = Some(err),
+        }
+    }
+
+    fn check<T>(&mut self, result: Result<T, SynError>) -> Option<T> {
+        result.map_err(|err| self.push(err)).ok()
+    }
+
+    fn finish(self) -> Result<(), SynError> {
+        self.0.map_or(Ok(()), Err)
+    }
+}
+
+fn generic_params(generics: &Generics) -> Vec<GenericParam> {
+    generics
+        .params
+        .iter()
+        .cloned()
+        .map(|mut param| {
+            match &mut param {
+                GenericParam::Type(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                GenericParam::Const(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                GenericParam::Lifetime(_) => {}
+            }
+            param
+        })
+        .collect()
+}
+
+fn generic_args(generics: &Generics) -> Vec<TokenStream2> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect()
+}
+
+// The builder, error and edit types generated for the type of a
+// `sub_builder` field, which are named after it and live next to it.
+fn sub_builder_types(ty: &Type) -> Option<(Type, Type, Type)> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let last = path.segments.last()?;
+    let mut builder = path.clone();
+    let mut error = path.clone();
+    let mut edit = path.clone();
+    *builder.segments.last_mut()? = PathSegment {
+        ident: format_ident!("{}Builder", last.ident),
+        arguments: last.arguments.clone(),
+    };
+    *error.segments.last_mut()? = PathSegment::from(format_ident!("{}BuilderError", last.ident));
+    *edit.segments.last_mut()? = PathSegment {
+        ident: format_ident!("{}BuilderEdit", last.ident),
+        arguments: last.arguments.clone(),
+    };
+    Some((
+        parse_quote! { #builder },
+        parse_quote! { #error },
+        parse_quote! { #edit },
+    ))
+}
+
+fn typestate_param(ident: &Ident) -> Ident {
+    format_ident!("__{}", camel_case(ident))
+}
+
+fn missing_variant(ident: &Ident) -> Ident {
+    format_ident!("Missing{}", camel_case(ident))
+}
+
+fn invalid_variant(ident: &Ident) -> Ident {
+    format_ident!("Invalid{}", camel_case(ident))
+}
+
+// Raw identifiers lose their `r#`, which is not valid inside another name.
+fn camel_case(ident: &Ident) -> String {
+    ident
+        .unraw()
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn snake_case(ident: &Ident) -> String {
+    let mut snake = String::new();
+    for (i, c) in ident.unraw().to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+// Every struct and enum of the `tests/expand/*.rs` files is followed by its
+// expansion, pretty-printed and compared against the `.expanded.rs` file next
+// to it. Run with `EXPAND=overwrite` to accept a change of the expansion.
+// `builder(serde)` is left out as it depends on a feature.
+#[cfg(test)]
+mod tests {
+    use super::expand;
+    use quote::ToTokens;
+    use std::{env, fs, path::Path};
+    use syn::{File, Item};
+
+    fn expand_file(source: &str) -> String {
+        let file = syn::parse_file(source).unwrap();
+        let mut items = Vec::new();
+        for item in file.items {
+            let expanded = match &item {
+                Item::Struct(_) | Item::Enum(_) => {
+                    let expanded = expand(item.to_token_stream());
+                    syn::parse2::<File>(expanded).unwrap().items
+                }
+                _ => Vec::new(),
+            };
+            items.push(item);
+            items.extend(expanded);
+        }
+        prettyplease::unparse(&File {
+            shebang: None,
+            attrs: file.attrs,
+            items,
+        })
+    }
+
+    #[test]
+    fn expansion() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+        let overwrite = env::var_os("EXPAND").is_some_and(|value| value == "overwrite");
+        let mut inputs = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.file_name().unwrap().to_string_lossy();
+                // `builder(serde)` is rejected without the feature.
+                name.ends_with(".rs")
+                    && !name.ends_with(".expanded.rs")
+                    && (cfg!(feature = "serde") || !name.starts_with("serde"))
+            })
+            .collect::<Vec<_>>();
+        inputs.sort();
+        assert!(!inputs.is_empty(), "no expansion tests found");
+
+        let mut changed = Vec::new();
+        for input in inputs {
+            let actual = expand_file(&fs::read_to_string(&input).unwrap());
+            let golden = input.with_extension("expanded.rs");
+            if overwrite {
+                fs::write(&golden, actual).unwrap();
+            } else if fs::read_to_string(&golden).ok().as_deref() != Some(actual.as_str()) {
+                changed.push(golden.display().to_string());
+            }
+        }
+        assert!(
+            changed.is_empty(),
+            "the expansion differs from {}, rerun with EXPAND=overwrite to accept it",
+            changed.join(", "),
+        );
+    }
 }
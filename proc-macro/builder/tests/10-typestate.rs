@@ -0,0 +1,59 @@
+// With #[builder(typestate)] the builder tracks in its type which of the
+// required fields have been set, so `build` is only callable once all of them
+// are present and can return the target directly instead of a Result.
+//
+// Setters in this mode take the builder by value, because setting a required
+// field changes the type of the builder.
+//
+//     impl<__Executable> CommandBuilder<__Executable> {
+//         pub fn executable(self, executable: String) -> CommandBuilder<(String,)> {
+//             ...
+//         }
+//     }
+//
+//     impl CommandBuilder<(String,)> {
+//         pub fn build(self) -> Command {
+//             ...
+//         }
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    env: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Item {
+    r#type: String,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .env(vec![])
+        .executable("cargo".to_owned())
+        .arg("--release".to_owned())
+        .build();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert!(command.env.is_empty());
+    assert_eq!(command.current_dir, None);
+
+    let builder = Command::builder().executable("rustc".to_owned());
+    let builder = builder.current_dir("..".to_owned());
+    let command = builder.executable("cargo".to_owned()).env(vec![]).build();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let item = Item::builder().r#type("a".to_owned()).build();
+    assert_eq!(item.r#type, "a");
+}
@@ -0,0 +1,69 @@
+// Instead of a Box<dyn Error> the build method returns a generated error enum,
+// CommandBuilderError, with one variant per required field. Every missing
+// field is reported, not just the first one. Raw identifiers are named without
+// their `r#`.
+//
+//     pub enum CommandBuilderError {
+//         MissingExecutable,
+//         MissingCurrentDir,
+//         Validation {
+//             field: Option<&'static str>,
+//             message: String,
+//         },
+//         Multiple(Vec<CommandBuilderError>),
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    current_dir: String,
+    env: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Item {
+    r#type: String,
+}
+
+fn main() {
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err, CommandBuilderError::MissingCurrentDir);
+    assert_eq!(err.to_string(), "`current_dir` is required");
+
+    let err = Command::builder().build().err().unwrap();
+    assert_eq!(
+        err,
+        CommandBuilderError::Multiple(vec![
+            CommandBuilderError::MissingExecutable,
+            CommandBuilderError::MissingArgs,
+            CommandBuilderError::MissingCurrentDir,
+        ])
+    );
+    assert_eq!(
+        err.to_string(),
+        "`executable` is required, `args` is required, `current_dir` is required",
+    );
+
+    let boxed: Box<dyn std::error::Error> = Box::new(err);
+    assert!(boxed.to_string().starts_with("`executable`"));
+
+    let err = Item::builder().build().err().unwrap();
+    assert_eq!(err, ItemBuilderError::MissingType);
+    assert_eq!(err.to_string(), "`type` is required");
+    assert_eq!(
+        Item::builder()
+            .r#type("a".to_owned())
+            .build()
+            .unwrap()
+            .r#type,
+        "a"
+    );
+}
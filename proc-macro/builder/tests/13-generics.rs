@@ -0,0 +1,71 @@
+// Generic structs carry their lifetimes, type and const parameters and where
+// clauses over to the builder, both in the default and in the typestate mode.
+//
+//     impl<'a, T: Clone, const N: usize> RequestBuilder<'a, T, N>
+//     where
+//         T: Default,
+//     {
+//         ...
+//     }
+
+use derive_builder::Builder;
+use std::fmt::Debug;
+
+#[derive(Builder)]
+pub struct Request<'a, T: Clone, const N: usize>
+where
+    T: Default,
+{
+    path: &'a str,
+    body: T,
+    headers: [u8; N],
+    #[builder(each = "tag")]
+    tags: Vec<&'a str>,
+    timeout: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Call<'a, T: Debug = String> {
+    method: &'a str,
+    payload: T,
+    retries: Option<u8>,
+}
+
+fn main() {
+    let path = String::from("/index.html");
+    let request = Request::builder()
+        .path(&path)
+        .body(vec![1u8, 2, 3])
+        .headers([0; 4])
+        .tag("static")
+        .build()
+        .unwrap();
+    assert_eq!(request.path, "/index.html");
+    assert_eq!(request.body, [1, 2, 3]);
+    assert_eq!(request.headers.len(), 4);
+    assert_eq!(request.tags, ["static"]);
+    assert_eq!(request.timeout, None);
+
+    let err = Request::<String, 0>::builder()
+        .path("/")
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        RequestBuilderError::Multiple(vec![
+            RequestBuilderError::MissingBody,
+            RequestBuilderError::MissingHeaders,
+        ])
+    );
+
+    let call: Call = Call::builder()
+        .payload("ping".to_owned())
+        .method("GET")
+        .retries(3)
+        .build();
+    assert_eq!(call.method, "GET");
+    assert_eq!(call.payload, "ping");
+    assert_eq!(call.retries, Some(3));
+}
@@ -0,0 +1,90 @@
+// Fields marked #[builder(default)] fall back to Default::default() when their
+// setter was never called, and #[builder(default = "...")] to an arbitrary
+// expression evaluated inside `build`. Fields are bound in declaration order, so
+// the expression may refer to the fields declared before it.
+//
+// On the struct, #[builder(default)] seeds every field that was not set from
+// the target's own Default impl.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(default)]
+    args: Vec<String>,
+    #[builder(default = "format!(\"/usr/bin/{}\", executable)")]
+    path: String,
+    #[builder(default = "Some(\"..\".to_owned())")]
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(default)]
+pub struct Config {
+    name: String,
+    port: u16,
+    #[builder(default = "port + 1")]
+    admin_port: u16,
+    #[builder(each = "host")]
+    hosts: Vec<String>,
+    timeout: Option<u32>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            name: "prod".to_owned(),
+            port: 80,
+            admin_port: 0,
+            hosts: vec!["localhost".to_owned()],
+            timeout: Some(30),
+        }
+    }
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    #[builder(default = "3")]
+    retries: u8,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert!(command.args.is_empty());
+    assert_eq!(command.path, "/usr/bin/cargo");
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .path("cargo".to_owned())
+        .current_dir("/".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.path, "cargo");
+    assert_eq!(command.current_dir.as_deref(), Some("/"));
+
+    assert_eq!(
+        Command::builder().build().err(),
+        Some(CommandBuilderError::MissingExecutable),
+    );
+
+    let config = Config::builder()
+        .port(8080)
+        .host("example.com".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(config.name, "prod");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.admin_port, 8081);
+    assert_eq!(config.hosts, ["localhost", "example.com"]);
+    assert_eq!(config.timeout, Some(30));
+
+    let job = Job::builder().name("backup".to_owned()).build();
+    assert_eq!(job.retries, 3);
+}
@@ -0,0 +1,73 @@
+// Besides the default mutable pattern, #[builder(pattern = "owned")] generates
+// setters that take and return the builder by value and a `build(self)` that
+// moves the fields out, so field types do not need to implement Clone.
+//
+// #[builder(pattern = "immutable")] generates setters taking `&self` that
+// return an updated copy of the builder, leaving the original untouched. Their
+// locals do not clash with a field named `builder`.
+
+use derive_builder::Builder;
+use std::sync::mpsc::{self, Receiver};
+
+pub struct Handle(u32);
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Worker {
+    handle: Handle,
+    inbox: Receiver<String>,
+    #[builder(each = "tag")]
+    tags: Vec<Handle>,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "immutable")]
+pub struct Endpoint {
+    host: String,
+    port: u16,
+    path: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "immutable", try_setter)]
+pub struct Job {
+    builder: String,
+    retries: u8,
+}
+
+fn main() {
+    let (sender, receiver) = mpsc::channel();
+    let worker = Worker::builder()
+        .handle(Handle(7))
+        .inbox(receiver)
+        .tag(Handle(1))
+        .build()
+        .unwrap();
+    sender.send("ping".to_owned()).unwrap();
+    assert_eq!(worker.handle.0, 7);
+    assert_eq!(worker.inbox.recv().unwrap(), "ping");
+    assert_eq!(worker.tags.len(), 1);
+    assert_eq!(worker.name, None);
+
+    let err = Worker::builder().handle(Handle(1)).build().err().unwrap();
+    assert_eq!(err, WorkerBuilderError::MissingInbox);
+
+    let base = Endpoint::builder().host("localhost".to_owned());
+    let http = base.port(80);
+    let admin = base.port(8080).path("/admin".to_owned());
+    assert_eq!(http.build().unwrap().port, 80);
+    let admin = admin.build().unwrap();
+    assert_eq!(admin.port, 8080);
+    assert_eq!(admin.path.as_deref(), Some("/admin"));
+    assert_eq!(base.build().err(), Some(EndpointBuilderError::MissingPort));
+
+    let job = Job::builder()
+        .builder("docker".to_owned())
+        .try_retries(3u32)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(job.builder, "docker");
+    assert_eq!(job.retries, 3);
+}
@@ -0,0 +1,12 @@
+// Typestate builders change their type with every required setter, so they can
+// only ever be used by value.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate, pattern = "mutable")]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
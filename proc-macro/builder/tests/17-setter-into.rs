@@ -0,0 +1,62 @@
+// #[builder(setter(into))] makes a setter generic over anything convertible
+// into the field type, on a single field or on every field of the struct.
+//
+//     pub fn path(&mut self, path: impl Into<PathBuf>) -> &mut Self
+//
+// #[builder(setter(strip_option))] on an Option field additionally generates a
+// `<field>_opt` setter that takes the Option itself.
+
+use derive_builder::Builder;
+use std::path::PathBuf;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(into))]
+    executable: String,
+    #[builder(each = "arg", setter(into))]
+    args: Vec<String>,
+    #[builder(setter(into, strip_option))]
+    current_dir: Option<PathBuf>,
+    #[builder(setter(strip_option))]
+    timeout: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(typestate, setter(into))]
+pub struct Mount {
+    source: PathBuf,
+    target: PathBuf,
+    options: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .arg("build")
+        .arg(String::from("--release"))
+        .current_dir("/tmp")
+        .timeout_opt(None)
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, ["build", "--release"]);
+    assert_eq!(command.current_dir, Some(PathBuf::from("/tmp")));
+    assert_eq!(command.timeout, None);
+
+    let command = Command::builder()
+        .executable("rustc")
+        .current_dir_opt(None)
+        .timeout(5)
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.timeout, Some(5));
+
+    let mount = Mount::builder()
+        .source("/dev/sda1")
+        .target("/mnt")
+        .options("ro")
+        .build();
+    assert_eq!(mount.source, PathBuf::from("/dev/sda1"));
+    assert_eq!(mount.options.as_deref(), Some("ro"));
+}
@@ -0,0 +1,143 @@
+// #[builder(validate = "path")] on a field runs the given function against the
+// field's final value inside `build`, and #[builder(build_fn(validate = "path"))]
+// on the struct runs a function against the whole builder. A failing validator
+// is reported as CommandBuilderError::Validation, and `build` collects every
+// failure instead of stopping at the first one. Fields are validated even when
+// a required field is missing, as long as their value does not depend on it.
+// The locals of `build` do not clash with fields such as `errors`.
+//
+//     fn validate_port(port: &u16) -> Result<(), String>;
+//     fn validate_builder(builder: &ServerBuilder) -> Result<(), String>;
+
+use derive_builder::Builder;
+
+fn non_zero(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        return Err("port must not be 0".to_owned());
+    }
+    Ok(())
+}
+
+fn not_empty(hosts: &[String]) -> Result<(), &'static str> {
+    if hosts.is_empty() {
+        return Err("no hosts given");
+    }
+    Ok(())
+}
+
+fn check_tls(builder: &ServerBuilder) -> Result<(), String> {
+    match (builder.tls, builder.port) {
+        (Some(true), Some(80)) => Err("tls cannot be served on port 80".to_owned()),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Builder)]
+#[builder(build_fn(validate = "check_tls"))]
+pub struct Server {
+    #[builder(validate = "non_zero")]
+    port: u16,
+    #[builder(each = "host", validate = "not_empty")]
+    hosts: Vec<String>,
+    tls: Option<bool>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Limit {
+    #[builder(validate = "non_zero")]
+    max: u16,
+}
+
+#[derive(Builder)]
+pub struct Report {
+    name: String,
+    #[builder(validate = "not_empty")]
+    errors: Vec<String>,
+}
+
+fn main() {
+    let server = Server::builder()
+        .port(443)
+        .host("localhost".to_owned())
+        .tls(true)
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 443);
+
+    let err = Server::builder().port(0).build().err().unwrap();
+    assert_eq!(
+        err,
+        ServerBuilderError::Multiple(vec![
+            ServerBuilderError::Validation {
+                field: Some("port"),
+                message: "port must not be 0".to_owned(),
+            },
+            ServerBuilderError::Validation {
+                field: Some("hosts"),
+                message: "no hosts given".to_owned(),
+            },
+        ])
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid `port`: port must not be 0, invalid `hosts`: no hosts given",
+    );
+
+    let err = Server::builder().tls(true).port(80).build().err().unwrap();
+    assert_eq!(
+        err,
+        ServerBuilderError::Multiple(vec![
+            ServerBuilderError::Validation {
+                field: None,
+                message: "tls cannot be served on port 80".to_owned(),
+            },
+            ServerBuilderError::Validation {
+                field: Some("hosts"),
+                message: "no hosts given".to_owned(),
+            },
+        ])
+    );
+
+    let err = Server::builder().tls(false).build().err().unwrap();
+    assert_eq!(
+        err,
+        ServerBuilderError::Multiple(vec![
+            ServerBuilderError::MissingPort,
+            ServerBuilderError::Validation {
+                field: Some("hosts"),
+                message: "no hosts given".to_owned(),
+            },
+        ])
+    );
+
+    let report = Report::builder()
+        .name("lint".to_owned())
+        .errors(vec!["unused".to_owned()])
+        .build()
+        .unwrap();
+    assert_eq!(report.errors, ["unused"]);
+    assert!(matches!(
+        Report::builder()
+            .name("lint".to_owned())
+            .errors(vec![])
+            .build(),
+        Err(ReportBuilderError::Validation { .. }),
+    ));
+    assert_eq!(
+        Report::builder().errors(vec![]).build().err().unwrap(),
+        ReportBuilderError::Multiple(vec![
+            ReportBuilderError::MissingName,
+            ReportBuilderError::Validation {
+                field: Some("errors"),
+                message: "no hosts given".to_owned(),
+            },
+        ])
+    );
+
+    assert_eq!(Limit::builder().max(10).build().unwrap().max, 10);
+    assert!(matches!(
+        Limit::builder().max(0).build(),
+        Err(LimitBuilderError::Validation { .. }),
+    ));
+}
@@ -0,0 +1,59 @@
+// Tuple structs get a builder too. Their fields are set through `_0`, `_1`, ...
+// unless renamed with #[builder(name = "...")].
+//
+// On an enum, every variant gets its own builder, `{Enum}{Variant}Builder`,
+// created through `Enum::<snake_case_variant>_builder()` and building the
+// variant as a value of the enum. Attributes on the enum apply to every
+// variant; attributes on a variant apply only to that variant.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Rgb(u8, #[builder(name = "green")] u8, #[builder(default)] u8);
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(setter(into))]
+pub enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[builder(typestate)]
+    Rect(f64, f64),
+    Polygon {
+        #[builder(each = "point")]
+        points: Vec<(f64, f64)>,
+        label: Option<String>,
+    },
+}
+
+fn main() {
+    let color = Rgb::builder()._0(255).green(128).build().unwrap();
+    assert_eq!((color.0, color.1, color.2), (255, 128, 0));
+
+    let err = Rgb::builder()._0(1).build().err().unwrap();
+    assert_eq!(err, RgbBuilderError::MissingGreen);
+
+    let circle = Shape::circle_builder().radius(1.5f32).build().unwrap();
+    assert_eq!(circle, Shape::Circle { radius: 1.5 });
+
+    let err = Shape::circle_builder().build().err().unwrap();
+    assert_eq!(err, ShapeCircleBuilderError::MissingRadius);
+
+    let rect: Shape = Shape::rect_builder()._1(2.0)._0(3.0).build();
+    assert_eq!(rect, Shape::Rect(3.0, 2.0));
+
+    let polygon = Shape::polygon_builder()
+        .point((0.0, 0.0))
+        .point((1.0, 0.0))
+        .point((0.0, 1.0))
+        .label("triangle")
+        .build()
+        .unwrap();
+    assert_eq!(
+        polygon,
+        Shape::Polygon {
+            points: vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+            label: Some("triangle".to_owned()),
+        },
+    );
+}
@@ -0,0 +1,64 @@
+// Every mistake in the #[builder(...)] attributes is reported in the same
+// compile, each pointing at the key or literal at fault: unknown keys, values
+// of the wrong kind, repeated keys, keys that only make sense on the struct or
+// only on a field, and keys that do not apply to the type or the other keys of
+// the field, such as setter options on a computed field. Conflicts with typestate builders name `required_ctor`
+// when that is what made the builder a typestate one.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(pattern = "owned", pattern = "mutable", each = "x", setter(into, onto))]
+#[builder]
+pub struct Command {
+    #[builder(each = 5)]
+    args: Vec<String>,
+    #[builder(typestate, defualt)]
+    env: String,
+    #[builder(default = 0, validate)]
+    current_dir: Option<String>,
+    #[builder(sub_builder, each = "host")]
+    hosts: Vec<String>,
+    #[builder(sub_builder)]
+    limits: [u32; 2],
+}
+
+#[derive(Builder)]
+#[builder(const, pattern = "mutable", default)]
+pub struct Table {
+    #[builder(each = "row")]
+    rows: Vec<u8>,
+    #[builder(default)]
+    size: usize,
+    #[builder(setter(into))]
+    name: &'static str,
+}
+
+#[derive(Builder)]
+#[builder(required_ctor, getters, const)]
+pub struct Server {
+    #[builder(getters)]
+    host: String,
+    #[builder(sub_builder)]
+    table: Table,
+}
+
+fn check(_: &u32) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Builder)]
+pub struct Job {
+    #[builder(each = "arg")]
+    args: Option<Vec<String>>,
+    #[builder(setter(strip_option))]
+    name: String,
+    #[builder(required, each = "tag")]
+    tags: Vec<String>,
+    #[builder(skip, setter(into), try_setter)]
+    id: u32,
+    #[builder(compute = "1", validate = "check")]
+    attempts: u32,
+}
+
+fn main() {}
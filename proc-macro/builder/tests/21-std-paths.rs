@@ -0,0 +1,82 @@
+// Option and Vec fields are recognised whether they are written as plain
+// `Option<T>` or through a full path such as `std::option::Option<T>`,
+// `::std::vec::Vec<T>` or `core::option::Option<T>`. Only these paths name
+// Option, so a type of a user's own that merely shares the name, like
+// `config::Option<T>`, is a required field.
+//
+// Neither aliases nor `use` can be seen through, so #[builder(optional)] marks
+// an alias of `Option<T>` as optional, and #[builder(required)] makes a field
+// required even though its type is spelled `Option`, as when a type of the
+// user's own is brought in under that name.
+
+use derive_builder::Builder;
+
+mod config {
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Option<T>(pub T);
+}
+
+type Port = Option<u16>;
+
+mod limits {
+    use super::config::Option;
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Limits {
+        #[builder(required)]
+        pub max: Option<u8>,
+    }
+}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: std::option::Option<String>,
+    #[builder(each = "arg")]
+    args: ::std::vec::Vec<String>,
+    #[builder(each = "env")]
+    env: std::vec::Vec<String>,
+    current_dir: core::option::Option<String>,
+    level: config::Option<u8>,
+    #[builder(optional, setter(strip_option))]
+    port: Port,
+    #[builder(required)]
+    timeout: Option<u32>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .env("RUST_LOG=debug".to_owned())
+        .level(config::Option(3))
+        .port(8080)
+        .timeout(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, None);
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.level, config::Option(3));
+    assert_eq!(command.port, Some(8080));
+    assert_eq!(command.timeout, None);
+
+    let err = Command::builder()
+        .level(config::Option(0))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err, CommandBuilderError::MissingTimeout);
+
+    let err = Command::builder().timeout(Some(5)).build().err().unwrap();
+    assert_eq!(err, CommandBuilderError::MissingLevel);
+
+    let limits = limits::Limits::builder()
+        .max(config::Option(4))
+        .build()
+        .unwrap();
+    assert_eq!(limits.max, config::Option(4));
+    let err = limits::Limits::builder().build().err().unwrap();
+    assert_eq!(err, limits::LimitsBuilderError::MissingMax);
+}
@@ -0,0 +1,81 @@
+// #[builder(each = "...")] works on any collection that is `Default` and
+// `Extend`s with the items it yields, not only on `Vec`. The per-item setter of
+// a map takes the key and the value as two arguments.
+//
+// Every `each` field also gets an `extend_<setter>` setter adding all items of
+// an iterator at once, named after the setter of the whole field so that it
+// follows `setter(name = "...")`.
+//
+//     impl CommandBuilder {
+//         fn header(&mut self, key: String, value: String) -> &mut Self;
+//         fn extend_headers(
+//             &mut self,
+//             headers: impl IntoIterator<Item = (String, String)>,
+//         ) -> &mut Self;
+//     }
+
+use derive_builder::Builder;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+#[derive(Builder)]
+#[builder(setter(into))]
+pub struct Request {
+    #[builder(each = "header")]
+    headers: HashMap<String, String>,
+    #[builder(each = "param")]
+    params: BTreeMap<&'static str, u32>,
+    #[builder(each = "tag")]
+    tags: std::collections::HashSet<String>,
+    #[builder(each = "flag")]
+    flags: BTreeSet<char>,
+    #[builder(each = "retry", setter(name = "delays"))]
+    retries: VecDeque<u64>,
+    #[builder(each = "byte")]
+    body: Body,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Body(Vec<u8>);
+
+impl Extend<u8> for Body {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for Body {
+    type Item = u8;
+    type IntoIter = std::vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+fn main() {
+    let request = Request::builder()
+        .header("accept", "*/*")
+        .extend_headers([("host".to_owned(), "localhost".to_owned())])
+        .param("page", 2u8)
+        .param("limit", 10u8)
+        .tag("a")
+        .tag("a")
+        .extend_flags(['v', 'q'])
+        .retry(100u32)
+        .extend_delays([200, 400])
+        .byte(b'o')
+        .extend_body(*b"k")
+        .build()
+        .unwrap();
+
+    assert_eq!(request.headers.len(), 2);
+    assert_eq!(request.headers["host"], "localhost");
+    assert_eq!(
+        request.params.into_iter().collect::<Vec<_>>(),
+        [("limit", 10), ("page", 2)]
+    );
+    assert_eq!(request.tags, HashSet::from(["a".to_owned()]));
+    assert_eq!(request.flags, BTreeSet::from(['q', 'v']));
+    assert_eq!(request.retries, [100, 200, 400]);
+    assert_eq!(request.body, Body(b"ok".to_vec()));
+}
@@ -0,0 +1,44 @@
+// A field with #[builder(each = "...")] keeps its all-at-once setter next to
+// the one-at-a-time one whenever their names differ. The all-at-once setter
+// replaces what was collected so far, while the `each` setter appends.
+//
+// #[builder(setter(name = "..."))] renames the setters of a field without
+// renaming the field itself.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(name = "program"))]
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: Vec<String>,
+    #[builder(setter(name = "cwd", strip_option))]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .program("cargo".to_owned())
+        .arg("test".to_owned())
+        .args(vec!["build".to_owned()])
+        .arg("--release".to_owned())
+        .env("RUST_LOG=debug".to_owned())
+        .cwd("..".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, ["build", "--release"]);
+    assert_eq!(command.env, ["RUST_LOG=debug"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .program("cargo".to_owned())
+        .cwd_opt(None)
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir, None);
+}
@@ -0,0 +1,16 @@
+// The name given to an `each` setter, or to any renamed setter, must not be
+// the name of a setter of another field. The clash is reported at the name
+// instead of as a duplicate method definition in the generated code.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "executable")]
+    args: Vec<String>,
+    #[builder(setter(name = "args"))]
+    env: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,58 @@
+// Attributes on the struct customise the generated API:
+//
+//   - name = "..."                  the name of the builder, whose error type
+//                                   is the builder name followed by `Error`;
+//   - vis = "..."                   the visibility of the builder, its error
+//                                   type, its constructor and `build`;
+//   - derive(...)                   derives added to the builder;
+//   - build_fn(name = "...")        the name of `build`;
+//   - constructor(name = "...")     the name of `Target::builder`.
+
+mod command {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    #[builder(
+        name = "CmdBuilder",
+        vis = "pub(crate)",
+        derive(Debug, Clone, PartialEq),
+        build_fn(name = "finish"),
+        constructor(name = "new_builder")
+    )]
+    pub struct Command {
+        pub executable: String,
+        #[builder(each = "arg")]
+        pub args: Vec<String>,
+    }
+
+    #[derive(Builder)]
+    #[builder(typestate, derive(Debug), build_fn(name = "finish"))]
+    pub struct Job {
+        pub name: String,
+    }
+}
+
+use command::{CmdBuilder, CmdBuilderError, Command, Job};
+
+fn main() {
+    let mut builder: CmdBuilder = Command::new_builder();
+    builder.executable("cargo".to_owned());
+    let snapshot = builder.clone();
+    builder.arg("build".to_owned());
+
+    assert_ne!(builder, snapshot);
+    assert_eq!(
+        format!("{:?}", snapshot),
+        r#"CmdBuilder { executable: Some("cargo"), args: [] }"#,
+    );
+
+    let command = builder.finish().unwrap();
+    assert_eq!(command.args, ["build"]);
+
+    let err: CmdBuilderError = Command::new_builder().finish().err().unwrap();
+    assert_eq!(err, CmdBuilderError::MissingExecutable);
+
+    let job = Job::builder().name("backup".to_owned());
+    assert!(format!("{:?}", job).starts_with("JobBuilder"));
+    assert_eq!(job.finish().name, "backup");
+}
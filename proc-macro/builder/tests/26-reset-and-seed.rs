@@ -0,0 +1,72 @@
+// Builders implement Default, which gives the same empty builder as
+// `Target::builder()`, and can be emptied again with `clear()`, or one field
+// at a time with `reset_<setter>()`. It is named after the setter of the field,
+// so a field renamed with `setter(name = "...")` is reset under that name and
+// the first field of a tuple struct with `reset_0()`.
+//
+// A builder can also start out from an existing value, either by converting
+// the value with `From` or by calling `to_builder` on a value that is `Clone`,
+// so that a modified copy takes one setter call per changed field.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+pub struct Config {
+    name: String,
+    #[builder(default = "30")]
+    timeout: u32,
+    #[builder(each = "host")]
+    hosts: Vec<String>,
+    #[builder(setter(name = "via"))]
+    proxy: Option<String>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Size(u32, #[builder(default)] u32);
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    retries: Option<u8>,
+}
+
+fn main() {
+    let prod = Config::builder()
+        .name("prod".to_owned())
+        .host("a.example.com".to_owned())
+        .via("proxy.example.com".to_owned())
+        .build()
+        .unwrap();
+
+    let quick = prod.to_builder().timeout(5).build().unwrap();
+    assert_eq!(quick.name, "prod");
+    assert_eq!(quick.timeout, 5);
+    assert_eq!(quick.hosts, prod.hosts);
+
+    let mut builder = ConfigBuilder::from(prod.clone());
+    builder.reset_via().reset_timeout();
+    let direct = builder.build().unwrap();
+    assert_eq!(direct.proxy, None);
+    assert_eq!(direct.timeout, 30);
+
+    builder.clear();
+    assert_eq!(builder.build(), Err(ConfigBuilderError::MissingName));
+
+    let mut builder = ConfigBuilder::default();
+    builder.name("dev".to_owned());
+    assert_eq!(builder.build().unwrap().timeout, 30);
+
+    let mut size = SizeBuilder::from(Size(1, 2));
+    size.reset_1();
+    assert_eq!(size.build(), Ok(Size(1, 0)));
+    size.reset_0();
+    assert_eq!(size.build(), Err(SizeBuilderError::Missing0));
+
+    let job = Job::builder().name("backup".to_owned()).retries(3).build();
+    let rerun = JobBuilder::from(job).reset_retries().build();
+    assert_eq!(rerun.retries, None);
+
+    let empty = JobBuilder::from(rerun).clear();
+    assert_eq!(empty.name("sync".to_owned()).build().name, "sync");
+}
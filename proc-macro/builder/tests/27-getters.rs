@@ -0,0 +1,71 @@
+// #[builder(getters)] on a field gives the builder a `<field>_ref` method
+// returning what has been set so far, without the defaults that `build` would
+// fill in.
+//
+// On the struct it does so for every field and also adds `is_complete`, which
+// tells whether `build` can succeed as far as required fields go, and
+// `missing_fields`, which names the required fields that are not set yet.
+// Those of a sub builder are named by their path, as in `tls.cert`.
+//
+//     impl CommandBuilder {
+//         pub fn executable_ref(&self) -> Option<&String>;
+//         pub fn is_complete(&self) -> bool;
+//         pub fn missing_fields(&self) -> Vec<String>;
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(getters)]
+pub struct Command {
+    executable: String,
+    target: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(default = "1")]
+    jobs: u8,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Limits {
+    #[builder(getters)]
+    cpu: u32,
+    memory: u64,
+}
+
+#[derive(Builder)]
+#[builder(getters)]
+pub struct Flags {
+    verbose: Option<bool>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert!(!builder.is_complete());
+    assert_eq!(builder.missing_fields(), ["executable", "target"]);
+
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned());
+    assert_eq!(builder.executable_ref().map(String::as_str), Some("cargo"));
+    assert_eq!(builder.target_ref(), None);
+    assert_eq!(builder.args_ref().map(Vec::len), Some(1));
+    assert_eq!(builder.jobs_ref(), None);
+    assert_eq!(builder.current_dir_ref(), None);
+    assert_eq!(builder.missing_fields(), ["target"]);
+
+    builder.target("x86_64".to_owned());
+    assert!(builder.is_complete());
+    assert!(builder.missing_fields().is_empty());
+    assert_eq!(builder.build().unwrap().jobs, 1);
+
+    let mut limits = Limits::builder();
+    limits.cpu(2).memory(1 << 30);
+    assert_eq!(limits.cpu_ref(), Some(&2));
+
+    let flags = Flags::builder();
+    assert!(flags.is_complete());
+    assert!(flags.missing_fields().is_empty());
+    assert_eq!(flags.verbose_ref(), None);
+}
@@ -0,0 +1,75 @@
+// #[builder(skip)] leaves a field out of the builder. `build` initialises it
+// with Default::default(), or with the expression given by
+// #[builder(default = "...")] when there is one.
+//
+// #[builder(compute = "expr")] also leaves a field out of the builder and sets
+// it to `expr` inside `build`, once every field that is not computed is bound,
+// so it may refer to fields declared after it.
+//
+// Skipping a `PhantomData` field leaves a type parameter out of the builder's
+// fields, which the builder still accepts.
+
+use derive_builder::Builder;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+fn hash(host: &str, port: u16) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (host, port).hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Builder)]
+pub struct Endpoint {
+    #[builder(compute = "format!(\"{}:{}{}\", host, port, path.as_deref().unwrap_or(\"/\"))")]
+    location: String,
+    host: String,
+    port: u16,
+    #[builder(compute = "format!(\"http://{}:{}\", host, port)")]
+    url: String,
+    #[builder(compute = "hash(&host, port)")]
+    hash: u64,
+    #[builder(skip)]
+    hits: u32,
+    #[builder(skip, default = "vec![\"init\"]")]
+    log: Vec<&'static str>,
+    path: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Id<T> {
+    value: u32,
+    #[builder(skip)]
+    _marker: PhantomData<T>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    #[builder(compute = "name.len()")]
+    name_len: usize,
+}
+
+fn main() {
+    let endpoint = Endpoint::builder()
+        .host("localhost".to_owned())
+        .port(8080)
+        .build()
+        .unwrap();
+    assert_eq!(endpoint.url, "http://localhost:8080");
+    assert_eq!(endpoint.hash, hash("localhost", 8080));
+    assert_eq!(endpoint.hits, 0);
+    assert_eq!(endpoint.log, ["init"]);
+    assert_eq!(endpoint.location, "localhost:8080/");
+
+    let copy = EndpointBuilder::from(endpoint).port(9090).build().unwrap();
+    assert_eq!(copy.url, "http://localhost:9090");
+
+    let id: Id<String> = Id::builder().value(7).build().unwrap();
+    assert_eq!(id.value, 7);
+
+    let job = Job::builder().name("backup".to_owned()).build();
+    assert_eq!(job.name_len, 6);
+}
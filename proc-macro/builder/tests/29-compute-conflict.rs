@@ -0,0 +1,15 @@
+// A computed field always gets its value from its expression, so it cannot
+// also be skipped or given a default.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Endpoint {
+    host: String,
+    #[builder(compute = "host.len()", default)]
+    len: usize,
+    #[builder(skip, compute = "host.is_empty()")]
+    empty: bool,
+}
+
+fn main() {}
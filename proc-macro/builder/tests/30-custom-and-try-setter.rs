@@ -0,0 +1,70 @@
+// #[builder(setter(custom))] keeps the field in the builder but leaves writing
+// its setter to the user, who can store into the builder's field directly.
+//
+// #[builder(try_setter)] adds a `try_<field>` setter converting its argument
+// with TryInto, returning the conversion error when it fails. On the struct it
+// applies to every field.
+//
+//     impl ServerBuilder {
+//         pub fn try_port<U: TryInto<u16>>(&mut self, port: U)
+//             -> Result<&mut Self, U::Error>;
+//     }
+
+use derive_builder::Builder;
+use std::time::Duration;
+
+pub struct Seconds(pub &'static str);
+
+impl TryFrom<Seconds> for Duration {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(seconds: Seconds) -> Result<Self, Self::Error> {
+        seconds.0.parse().map(Duration::from_secs)
+    }
+}
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(setter(custom))]
+    host: String,
+    #[builder(try_setter)]
+    port: u16,
+    #[builder(try_setter, setter(custom))]
+    timeout: Option<Duration>,
+}
+
+impl ServerBuilder {
+    pub fn host(&mut self, host: &str) -> &mut Self {
+        self.host = Some(host.to_lowercase());
+        self
+    }
+}
+
+#[derive(Builder)]
+#[builder(typestate, try_setter)]
+pub struct Limit {
+    max: u8,
+}
+
+fn main() {
+    let server = Server::builder()
+        .host("LOCALHOST")
+        .try_port(8080u32)
+        .unwrap()
+        .try_timeout(Seconds("5"))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.timeout, Some(Duration::from_secs(5)));
+
+    let mut builder = Server::builder();
+    assert!(builder.try_port(70000u32).is_err());
+    assert!(builder.try_timeout(Seconds("soon")).is_err());
+    assert_eq!(builder.port, None);
+
+    let limit = Limit::builder().try_max(200i32).unwrap().build();
+    assert_eq!(limit.max, 200);
+    assert!(Limit::builder().try_max(-1i32).is_err());
+}
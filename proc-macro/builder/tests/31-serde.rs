@@ -0,0 +1,90 @@
+// With the `serde` feature of derive_builder, #[builder(serde)] makes the
+// builder implement serde::Deserialize so a partial configuration can be read
+// from a file. Every field is optional in the input, and `each` fields are read
+// as the whole collection. Attributes given with #[builder(field_attr(...))]
+// such as #[serde(rename = "...")] apply to the field of the builder.
+//
+// `merge` layers one builder over another: fields set in `other` replace the
+// current values, items of `each` fields are appended and sub builders are
+// merged in turn.
+//
+//     impl ConfigBuilder {
+//         pub fn merge(&mut self, other: ConfigBuilder) -> &mut Self;
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(serde)]
+pub struct Config {
+    name: String,
+    port: u16,
+    #[builder(default = "30")]
+    timeout: u32,
+    #[builder(each = "host")]
+    hosts: Vec<String>,
+    user: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(serde, pattern = "owned")]
+pub struct Limit {
+    #[builder(field_attr(serde(rename = "maximum")))]
+    max: u16,
+}
+
+#[derive(Builder)]
+#[builder(serde, pattern = "owned")]
+pub struct Proxy {
+    name: String,
+    #[builder(sub_builder)]
+    upstream: Limit,
+}
+
+fn main() {
+    let mut builder: ConfigBuilder = serde_json::from_str(
+        r#"{
+            "name": "prod",
+            "port": 80,
+            "hosts": ["a.example.com"]
+        }"#,
+    )
+    .unwrap();
+    let local: ConfigBuilder = serde_json::from_str(
+        r#"{
+            "port": 8080,
+            "hosts": ["b.example.com"],
+            "user": "admin"
+        }"#,
+    )
+    .unwrap();
+    builder.merge(local).host("c.example.com".to_owned());
+
+    let config = builder.build().unwrap();
+    assert_eq!(config.name, "prod");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.timeout, 30);
+    assert_eq!(
+        config.hosts,
+        ["a.example.com", "b.example.com", "c.example.com"]
+    );
+    assert_eq!(config.user.as_deref(), Some("admin"));
+
+    let mut partial: ConfigBuilder = serde_json::from_str(r#"{"port": 1}"#).unwrap();
+    assert_eq!(partial.build().err(), Some(ConfigBuilderError::MissingName));
+
+    let limit: LimitBuilder = serde_json::from_str(r#"{"maximum": 1}"#).unwrap();
+    let limit = Limit::builder()
+        .max(5)
+        .merge(limit)
+        .merge(LimitBuilder::default())
+        .build()
+        .unwrap();
+    assert_eq!(limit.max, 1);
+
+    let proxy: ProxyBuilder = serde_json::from_str(r#"{"upstream": {"maximum": 2}}"#).unwrap();
+    let local: ProxyBuilder = serde_json::from_str(r#"{"name": "local"}"#).unwrap();
+    let proxy = proxy.merge(local).build().unwrap();
+    assert_eq!(proxy.name, "local");
+    assert_eq!(proxy.upstream.max, 2);
+}
@@ -0,0 +1,168 @@
+// #[builder(sub_builder)] on a field whose type derives Builder itself keeps a
+// builder of that type inside the outer one, which `build` builds along with
+// the other fields. It is edited in place through `<field>_mut` or by passing
+// a closure to the setter.
+//
+// A failing sub builder is reported as ServerBuilderError::InvalidTls, which
+// holds the error of the sub builder and displays the full path of the
+// offending field.
+//
+// The closure takes the sub builder the way its own setters do, as described
+// by the TlsBuilderEdit trait generated next to it, so the inner and outer
+// builders may use any pattern. Building by reference clones the fields of the
+// sub builder, and with the owned pattern the sub builder itself, which must
+// then derive Clone. An immutable outer builder clones itself on every setter,
+// so its sub builders must be Clone as well. Every struct builder has the
+// hidden methods and the `<Builder>Edit` trait this needs, as it cannot know
+// whether another builder holds it.
+//
+// With #[builder(getters)], `missing_fields` names the fields missing in sub
+// builders by their path.
+//
+//     pub trait TlsBuilderEdit: FnOnce(&mut TlsBuilder) -> &mut TlsBuilder {}
+//
+//     impl ServerBuilder {
+//         pub fn tls(&mut self, f: impl TlsBuilderEdit) -> &mut Self;
+//         pub fn tls_mut(&mut self) -> &mut TlsBuilder;
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+pub struct Tls {
+    cert: String,
+    #[builder(default)]
+    verify: bool,
+}
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+pub struct Server {
+    port: u16,
+    #[builder(sub_builder)]
+    tls: Tls,
+}
+
+#[derive(Builder, Debug)]
+#[builder(pattern = "owned", getters)]
+pub struct Config {
+    name: String,
+    #[builder(sub_builder)]
+    server: Server,
+}
+
+#[derive(Builder, Debug)]
+#[builder(pattern = "owned", derive(Clone))]
+pub struct Limits {
+    max: u32,
+}
+
+#[derive(Builder, Debug)]
+#[builder(pattern = "immutable")]
+pub struct Retry {
+    #[builder(default = "3")]
+    attempts: u8,
+}
+
+#[derive(Builder, Debug)]
+pub struct Service {
+    #[builder(sub_builder)]
+    limits: Limits,
+    #[builder(sub_builder)]
+    retry: Retry,
+}
+
+#[derive(Builder, Debug)]
+#[builder(pattern = "owned")]
+pub struct Worker {
+    #[builder(sub_builder)]
+    limits: Limits,
+    #[builder(sub_builder)]
+    retry: Retry,
+}
+
+#[derive(Builder, Debug)]
+#[builder(pattern = "immutable")]
+pub struct Cluster {
+    #[builder(sub_builder)]
+    retry: Retry,
+    #[builder(sub_builder)]
+    limits: Limits,
+}
+
+fn main() {
+    let mut builder = Server::builder();
+    builder.port(443).tls(|tls| tls.cert("cert.pem".to_owned()));
+    builder.tls_mut().verify(true);
+    let server = builder.build().unwrap();
+    assert_eq!(server.tls.cert, "cert.pem");
+    assert!(server.tls.verify);
+
+    let mut builder = ServerBuilder::from(server.clone());
+    builder.tls_mut().cert("other.pem".to_owned());
+    assert_eq!(builder.build().unwrap().tls.cert, "other.pem");
+
+    let err = Server::builder().build().err().unwrap();
+    assert_eq!(
+        err,
+        ServerBuilderError::Multiple(vec![
+            ServerBuilderError::MissingPort,
+            ServerBuilderError::InvalidTls(TlsBuilderError::MissingCert),
+        ])
+    );
+    assert_eq!(
+        err.to_string(),
+        "`port` is required, `tls.cert` is required"
+    );
+
+    let mut config = Config::builder();
+    assert!(!config.is_complete());
+    assert_eq!(
+        config.missing_fields(),
+        ["name", "server.port", "server.tls.cert"]
+    );
+    config = config.name("prod".to_owned());
+    config.server_mut().port(80);
+    assert_eq!(config.missing_fields(), ["server.tls.cert"]);
+    let err = config.build().err().unwrap();
+    assert_eq!(
+        err,
+        ConfigBuilderError::InvalidServer(ServerBuilderError::InvalidTls(
+            TlsBuilderError::MissingCert,
+        ))
+    );
+    assert_eq!(err.to_string(), "`server.tls.cert` is required");
+
+    let config = Config::builder()
+        .name("prod".to_owned())
+        .server(|server| server.port(80).tls(|tls| tls.cert("cert.pem".to_owned())))
+        .build()
+        .unwrap();
+    assert_eq!(config.server.port, 80);
+    assert_eq!(config.server.tls.cert, "cert.pem");
+
+    let mut builder = Service::builder();
+    builder
+        .limits(|limits| limits.max(10))
+        .retry(|retry| retry.attempts(5));
+    let service = builder.build().unwrap();
+    assert_eq!(service.limits.max, 10);
+    assert_eq!(service.retry.attempts, 5);
+    assert_eq!(
+        Service::builder().build().err().unwrap().to_string(),
+        "`limits.max` is required",
+    );
+
+    let worker = Worker::builder()
+        .limits(|limits| limits.max(1))
+        .build()
+        .unwrap();
+    assert_eq!(worker.limits.max, 1);
+    assert_eq!(worker.retry.attempts, 3);
+
+    let cluster = Cluster::builder()
+        .retry(|retry| retry.attempts(1))
+        .limits(|limits| limits.max(2));
+    let cluster = cluster.build().unwrap();
+    assert_eq!(cluster.retry.attempts, 1);
+    assert_eq!(cluster.limits.max, 2);
+}
@@ -0,0 +1,94 @@
+// #[builder(no_std)] makes the generated code name `core` and `alloc` instead
+// of `std`, so that builders can be derived in crates without the standard
+// library. The error type then only implements Display, as std::error::Error
+// is not available.
+//
+// Only the macro's own paths are affected, the field types and expressions of
+// the struct are kept as written, `std::` paths included.
+
+#![no_std]
+
+extern crate alloc;
+// The test still runs as a binary linking std, under a name the generated code
+// cannot reach.
+extern crate std as _;
+
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use derive_builder::Builder;
+
+// Stands in for the std of a crate that has one, without anything the
+// generated code could reach for.
+mod std {
+    pub use alloc::sync;
+}
+
+fn non_zero(ttl: &u8) -> Result<(), &'static str> {
+    if *ttl == 0 {
+        return Err("ttl must not be 0");
+    }
+    Ok(())
+}
+
+#[derive(Builder, Debug)]
+#[builder(no_std, getters)]
+pub struct Header {
+    id: u16,
+    #[builder(validate = "non_zero")]
+    ttl: u8,
+}
+
+#[derive(Builder, Debug)]
+#[builder(no_std)]
+pub struct Packet {
+    #[builder(sub_builder)]
+    header: Header,
+    #[builder(each = "byte")]
+    payload: Vec<u8>,
+    #[builder(default)]
+    tag: Option<String>,
+    #[builder(default = "std::sync::Arc::new(0)")]
+    shared: std::sync::Arc<u8>,
+}
+
+#[derive(Builder)]
+#[builder(no_std, pattern = "immutable")]
+pub struct Route {
+    #[builder(each = "hop")]
+    hops: Vec<u8>,
+}
+
+#[derive(Builder)]
+#[builder(no_std, typestate)]
+pub struct Frame {
+    len: usize,
+}
+
+fn main() {
+    let packet = Packet::builder()
+        .header(|header| header.id(1).ttl(64))
+        .byte(0xff)
+        .tag("ping".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(packet.header.id, 1);
+    assert_eq!(packet.payload, [0xff]);
+    assert_eq!(packet.tag.as_deref(), Some("ping"));
+    assert_eq!(*packet.shared, 0);
+
+    let mut header = Header::builder();
+    header.ttl(0);
+    assert_eq!(header.missing_fields(), ["id"]);
+
+    let err = Packet::builder()
+        .header(|header| header.id(1).ttl(0))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "invalid `header.ttl`: ttl must not be 0");
+
+    let route = Route::builder().hop(1).hop(2).build().unwrap();
+    assert_eq!(route.hops, [1, 2]);
+    assert_eq!(Frame::builder().len(3).build().len, 3);
+}
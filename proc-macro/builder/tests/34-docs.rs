@@ -0,0 +1,76 @@
+//! The doc comments of the fields are copied onto their setters and onto the
+//! fields of the builder, and every other generated item is documented, so the
+//! derive can be used in crates denying missing docs. Setters also note whether
+//! `build` needs the field to be set.
+//!
+//! #[builder(doc = "...")] replaces the generated docs of the builder type.
+
+#![deny(missing_docs)]
+
+use derive_builder::Builder;
+
+/// A TLS configuration.
+#[derive(Builder)]
+pub struct Tls {
+    /// Path of the certificate.
+    cert: String,
+}
+
+/// A server.
+#[derive(Builder)]
+#[builder(getters, try_setter, doc = "Builds a [`Server`] bit by bit.")]
+pub struct Server {
+    /// Port to listen on.
+    port: u16,
+    /// Hosts to serve.
+    #[builder(each = "host")]
+    hosts: Vec<String>,
+    #[builder(each = "alias")]
+    alias: Vec<String>,
+    /// Time out of idle connections, in seconds.
+    #[builder(setter(strip_option))]
+    timeout: Option<u64>,
+    #[builder(default)]
+    workers: usize,
+    /// TLS settings.
+    #[builder(sub_builder)]
+    tls: Tls,
+}
+
+/// A job.
+#[derive(Builder)]
+#[builder(typestate, try_setter)]
+pub struct Job {
+    /// Name of the job.
+    name: String,
+    #[builder(default = "3")]
+    retries: u8,
+}
+
+/// A shape.
+#[derive(Builder)]
+pub enum Shape {
+    /// A circle.
+    Circle {
+        /// Radius of the circle.
+        radius: f64,
+    },
+    /// A point.
+    Point(i32, i32),
+}
+
+fn main() {
+    let server = Server::builder()
+        .port(443)
+        .host("localhost".to_owned())
+        .timeout(30)
+        .tls(|tls| tls.cert("cert.pem".to_owned()))
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 443);
+
+    let job = Job::builder().name("backup".to_owned()).build();
+    assert_eq!(job.retries, 3);
+
+    let _ = Shape::circle_builder().radius(1.0).build();
+}
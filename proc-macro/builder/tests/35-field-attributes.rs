@@ -0,0 +1,47 @@
+// Fields disabled with #[cfg] are left out of the builder altogether, as the
+// derive only sees the fields that are enabled, and #[cfg_attr] may add
+// builder options depending on the configuration.
+//
+// #[builder(field_attr(...))] and #[builder(setter_attr(...))] forward
+// attributes to the field of the builder and to the setters of the field.
+
+#![deny(deprecated)]
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Server {
+    #[builder(setter_attr(deprecated = "use `listen` instead"))]
+    port: u16,
+    #[cfg(any())]
+    tls: UndefinedTlsConfig,
+    #[cfg(not(any()))]
+    host: String,
+    #[cfg_attr(not(any()), builder(default))]
+    workers: usize,
+    #[cfg_attr(any(), builder(each = "alias"))]
+    aliases: Vec<String>,
+    #[builder(field_attr(allow(dead_code)), setter_attr(inline, must_use))]
+    backlog: Option<u32>,
+}
+
+impl ServerBuilder {
+    pub fn listen(&mut self, port: u16) -> &mut Self {
+        #[allow(deprecated)]
+        self.port(port)
+    }
+}
+
+fn main() {
+    let server = Server::builder()
+        .listen(8080)
+        .host("localhost".to_owned())
+        .aliases(vec!["www".to_owned()])
+        .backlog(128)
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.workers, 0);
+    assert_eq!(server.aliases, ["www"]);
+    assert_eq!(server.backlog, Some(128));
+}
@@ -0,0 +1,56 @@
+// #[builder(const)] makes the constructor and the setters of single values
+// `const fn`s of the owned pattern, and adds `build_const`, which builds the
+// target in a const context. A missing field panics, which fails the
+// compilation when the builder is evaluated in a constant or static. `build`
+// is still available at runtime.
+//
+// The fields are moved out of the builder one by one, which a const fn only
+// allows for types without destructors, such as integers and `&'static str`.
+// Defaults need an expression, `default = "..."`, since `Default::default`
+// cannot be called in a const context.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const, setter(strip_option))]
+pub struct Server {
+    name: &'static str,
+    port: u16,
+    #[builder(default = "port + 1")]
+    admin_port: u16,
+    timeout: Option<u32>,
+    #[builder(skip, default = "\"/\"")]
+    root: &'static str,
+}
+
+const SERVERS: [Server; 2] = [
+    Server::builder().name("web").port(80).build_const(),
+    Server::builder()
+        .name("db")
+        .port(5432)
+        .admin_port(5000)
+        .timeout(30)
+        .build_const(),
+];
+
+static DEFAULT: Server = Server::builder()
+    .name("local")
+    .timeout_opt(None)
+    .port(8080)
+    .build_const();
+
+fn main() {
+    assert_eq!(SERVERS[0].admin_port, 81);
+    assert_eq!(SERVERS[0].timeout, None);
+    assert_eq!(SERVERS[0].root, "/");
+    assert_eq!(SERVERS[1].admin_port, 5000);
+    assert_eq!(SERVERS[1].timeout, Some(30));
+    assert_eq!(DEFAULT.name, "local");
+
+    let server = Server::builder().name("web").port(80).build().unwrap();
+    assert_eq!(server, SERVERS[0]);
+    assert_eq!(
+        Server::builder().name("web").build(),
+        Err(ServerBuilderError::MissingPort),
+    );
+}
@@ -0,0 +1,15 @@
+// Evaluating `build_const` without a required field fails the compilation,
+// with the panic message naming the field.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Server {
+    name: &'static str,
+    port: u16,
+}
+
+const SERVER: Server = Server::builder().name("web").build_const();
+
+fn main() {}
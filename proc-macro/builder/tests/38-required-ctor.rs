@@ -0,0 +1,58 @@
+// #[builder(required_ctor)] makes the constructor take the required fields, in
+// the order they are declared, instead of setting them through setters. The
+// builder is a typestate builder with every required field already set, so
+// `build` returns the target directly. The required fields can still be
+// overwritten, and `setter(into)` applies to the constructor's arguments too.
+//
+//     impl Command {
+//         pub fn builder(executable: String, cwd: impl Into<String>)
+//             -> CommandBuilder<(String,), (String,)>;
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(required_ctor)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(setter(into))]
+    cwd: String,
+    timeout: Option<u32>,
+    #[builder(default = "3")]
+    retries: u8,
+}
+
+#[derive(Builder)]
+#[builder(required_ctor)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Rect(f64, f64),
+}
+
+fn main() {
+    let command: Command = Command::builder("cargo".to_owned(), "/")
+        .arg("build".to_owned())
+        .timeout(10)
+        .build();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, ["build"]);
+    assert_eq!(command.cwd, "/");
+    assert_eq!(command.timeout, Some(10));
+    assert_eq!(command.retries, 3);
+
+    let command = Command::builder("cargo".to_owned(), "/")
+        .executable("rustc".to_owned())
+        .build();
+    assert_eq!(command.executable, "rustc");
+
+    match Shape::circle_builder(1.0).build() {
+        Shape::Circle { radius } => assert_eq!(radius, 1.0),
+        Shape::Rect(..) => unreachable!(),
+    }
+    match Shape::rect_builder(2.0, 3.0).build() {
+        Shape::Rect(width, height) => assert_eq!((width, height), (2.0, 3.0)),
+        Shape::Circle { .. } => unreachable!(),
+    }
+}
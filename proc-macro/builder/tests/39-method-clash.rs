@@ -0,0 +1,25 @@
+// Besides the setters, the builder has methods of its own, such as `clear` and
+// `build`, and methods derived from the fields, such as `reset_<setter>` and
+// the `<field>_ref` getters. A setter may not take any of these names either,
+// which is reported at the name of the field or setter.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    clear: bool,
+    #[builder(setter(name = "build"))]
+    target: String,
+    port: u16,
+    reset_port: bool,
+}
+
+#[derive(Builder)]
+#[builder(getters)]
+pub struct Query {
+    is_complete: bool,
+    limit: u32,
+    limit_ref: u32,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// The variants of the error and the type parameters of a typestate builder are
+// named after the fields in CamelCase, which drops underscores. Fields whose
+// names only differ in underscores would get the same variant or parameter, so
+// this is reported at the second field.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Record {
+    id: u32,
+    _id: u32,
+    a_b: u32,
+    a__b: u32,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Pair {
+    left_hand: u32,
+    left_hand_: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// #[builder(each = "...")] needs a collection implementing Default and Extend.
+// On any other type, the compiler errors point at the type of the field rather
+// than at the derive.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "count")]
+    counts: u32,
+}
+
+fn main() {}
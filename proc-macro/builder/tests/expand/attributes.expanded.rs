@@ -0,0 +1,205 @@
+use derive_builder::Builder;
+/// A command to run.
+#[derive(Builder)]
+pub struct Command {
+    /// The program to run.
+    #[builder(setter_attr(inline, must_use), field_attr(allow(dead_code)))]
+    executable: String,
+    #[builder(setter_attr(deprecated = "use `args` instead"))]
+    legacy_args: Option<String>,
+}
+impl Command {
+    /// Returns a new [`CommandBuilder`].
+    pub fn builder() -> CommandBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Command`], returned by [`Command::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`CommandBuilderEdit`] closure.
+pub struct CommandBuilder {
+    /// The program to run.
+    #[allow(dead_code)]
+    executable: std::option::Option<String>,
+    legacy_args: std::option::Option<String>,
+}
+impl std::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: std::option::Option::None,
+            legacy_args: std::option::Option::None,
+        }
+    }
+}
+impl CommandBuilder {
+    /// The program to run.
+    ///
+    /// This field is required.
+    #[inline]
+    #[must_use]
+    pub fn executable(&mut self, executable: String) -> &mut Self {
+        self.executable = std::option::Option::Some(executable);
+        self
+    }
+    /// Sets `legacy_args`.
+    ///
+    /// This field is optional.
+    #[deprecated = "use `args` instead"]
+    pub fn legacy_args(&mut self, legacy_args: String) -> &mut Self {
+        self.legacy_args = std::option::Option::Some(legacy_args);
+        self
+    }
+    /// Unsets `executable`, as in a new builder.
+    pub fn reset_executable(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self
+    }
+    /// Unsets `legacy_args`, as in a new builder.
+    pub fn reset_legacy_args(&mut self) -> &mut Self {
+        self.legacy_args = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self.legacy_args = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Command`] from the fields set so far.
+    ///
+    /// Fails with [`CommandBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Command, CommandBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl CommandBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Command, CommandBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Command, CommandBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.executable.is_none() {
+            __errors.push(CommandBuilderError::MissingExecutable);
+        }
+        if let (std::option::Option::Some(executable),) = (self.executable.clone(),) {
+            let legacy_args = self.legacy_args.clone();
+            if __errors.is_empty() {
+                std::result::Result::Ok(Command { executable, legacy_args })
+            } else {
+                std::result::Result::Err(CommandBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(CommandBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.executable.is_none() {
+            missing.push(std::format!("{}{}", path, "executable"));
+        }
+    }
+}
+/// Closures editing a [`CommandBuilder`], as taken by the setters of outer builders holding it.
+pub trait CommandBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CommandBuilder,
+    ) -> &'__edit mut CommandBuilder {}
+impl<__F> CommandBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CommandBuilder,
+    ) -> &'__edit mut CommandBuilder,
+{}
+impl std::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        let Command { executable, legacy_args } = value;
+        Self {
+            executable: std::option::Option::Some(executable),
+            legacy_args,
+        }
+    }
+}
+impl Command {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__builder> Command: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`CommandBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum CommandBuilderError {
+    /// `executable` was not set.
+    MissingExecutable,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<CommandBuilderError>),
+}
+impl CommandBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            CommandBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            CommandBuilderError::MissingExecutable => {
+                std::write!(f, "`{}` is required", field("executable"))
+            }
+            CommandBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            CommandBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            CommandBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for CommandBuilderError {}
@@ -0,0 +1,11 @@
+use derive_builder::Builder;
+
+/// A command to run.
+#[derive(Builder)]
+pub struct Command {
+    /// The program to run.
+    #[builder(setter_attr(inline, must_use), field_attr(allow(dead_code)))]
+    executable: String,
+    #[builder(setter_attr(deprecated = "use `args` instead"))]
+    legacy_args: Option<String>,
+}
@@ -0,0 +1,229 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+impl Command {
+    /// Returns a new [`CommandBuilder`].
+    pub fn builder() -> CommandBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Command`], returned by [`Command::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`CommandBuilderEdit`] closure.
+pub struct CommandBuilder {
+    executable: std::option::Option<String>,
+    args: std::option::Option<Vec<String>>,
+    current_dir: std::option::Option<String>,
+}
+impl std::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: std::option::Option::None,
+            args: std::option::Option::None,
+            current_dir: std::option::Option::None,
+        }
+    }
+}
+impl CommandBuilder {
+    /// Sets `executable`.
+    ///
+    /// This field is required.
+    pub fn executable(&mut self, executable: String) -> &mut Self {
+        self.executable = std::option::Option::Some(executable);
+        self
+    }
+    /// Sets `args`.
+    ///
+    /// This field is required.
+    pub fn args(&mut self, args: Vec<String>) -> &mut Self {
+        self.args = std::option::Option::Some(args);
+        self
+    }
+    /// Sets `current_dir`.
+    ///
+    /// This field is optional.
+    pub fn current_dir(&mut self, current_dir: String) -> &mut Self {
+        self.current_dir = std::option::Option::Some(current_dir);
+        self
+    }
+    /// Unsets `executable`, as in a new builder.
+    pub fn reset_executable(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self
+    }
+    /// Unsets `args`, as in a new builder.
+    pub fn reset_args(&mut self) -> &mut Self {
+        self.args = std::option::Option::None;
+        self
+    }
+    /// Unsets `current_dir`, as in a new builder.
+    pub fn reset_current_dir(&mut self) -> &mut Self {
+        self.current_dir = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self.args = std::option::Option::None;
+        self.current_dir = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Command`] from the fields set so far.
+    ///
+    /// Fails with [`CommandBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Command, CommandBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl CommandBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Command, CommandBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Command, CommandBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.executable.is_none() {
+            __errors.push(CommandBuilderError::MissingExecutable);
+        }
+        if self.args.is_none() {
+            __errors.push(CommandBuilderError::MissingArgs);
+        }
+        if let (std::option::Option::Some(executable), std::option::Option::Some(args))
+            = (self.executable.clone(), self.args.clone()) {
+            let current_dir = self.current_dir.clone();
+            if __errors.is_empty() {
+                std::result::Result::Ok(Command {
+                    executable,
+                    args,
+                    current_dir,
+                })
+            } else {
+                std::result::Result::Err(CommandBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(CommandBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.executable.is_none() {
+            missing.push(std::format!("{}{}", path, "executable"));
+        }
+        if self.args.is_none() {
+            missing.push(std::format!("{}{}", path, "args"));
+        }
+    }
+}
+/// Closures editing a [`CommandBuilder`], as taken by the setters of outer builders holding it.
+pub trait CommandBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CommandBuilder,
+    ) -> &'__edit mut CommandBuilder {}
+impl<__F> CommandBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CommandBuilder,
+    ) -> &'__edit mut CommandBuilder,
+{}
+impl std::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        let Command { executable, args, current_dir } = value;
+        Self {
+            executable: std::option::Option::Some(executable),
+            args: std::option::Option::Some(args),
+            current_dir,
+        }
+    }
+}
+impl Command {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__builder> Command: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`CommandBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum CommandBuilderError {
+    /// `executable` was not set.
+    MissingExecutable,
+    /// `args` was not set.
+    MissingArgs,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<CommandBuilderError>),
+}
+impl CommandBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            CommandBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            CommandBuilderError::MissingExecutable => {
+                std::write!(f, "`{}` is required", field("executable"))
+            }
+            CommandBuilderError::MissingArgs => {
+                std::write!(f, "`{}` is required", field("args"))
+            }
+            CommandBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            CommandBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            CommandBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for CommandBuilderError {}
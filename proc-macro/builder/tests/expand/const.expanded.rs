@@ -0,0 +1,244 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(const, setter(strip_option))]
+pub struct Server {
+    name: &'static str,
+    #[builder(default = "80")]
+    port: u16,
+    timeout: Option<u32>,
+}
+impl Server {
+    /// Returns a new [`ServerBuilder`].
+    pub const fn builder() -> ServerBuilder {
+        ServerBuilder {
+            name: std::option::Option::None,
+            port: std::option::Option::None,
+            timeout: std::option::Option::None,
+        }
+    }
+}
+/// Builder for [`Server`], returned by [`Server::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`ServerBuilderEdit`] closure.
+pub struct ServerBuilder {
+    name: std::option::Option<&'static str>,
+    port: std::option::Option<u16>,
+    timeout: std::option::Option<u32>,
+}
+impl std::default::Default for ServerBuilder {
+    fn default() -> Self {
+        ServerBuilder {
+            name: std::option::Option::None,
+            port: std::option::Option::None,
+            timeout: std::option::Option::None,
+        }
+    }
+}
+impl ServerBuilder {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub const fn name(mut self, name: &'static str) -> Self {
+        self.name = std::option::Option::Some(name);
+        self
+    }
+    /// Sets `port`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub const fn port(mut self, port: u16) -> Self {
+        self.port = std::option::Option::Some(port);
+        self
+    }
+    /// Sets `timeout`.
+    ///
+    /// This field is optional.
+    pub const fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = std::option::Option::Some(timeout);
+        self
+    }
+    /// Sets `timeout` to an `Option`, `None` unsets it.
+    pub const fn timeout_opt(mut self, timeout: std::option::Option<u32>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+    /// Unsets `name`, as in a new builder.
+    pub fn reset_name(mut self) -> Self {
+        self.name = std::option::Option::None;
+        self
+    }
+    /// Unsets `port`, as in a new builder.
+    pub fn reset_port(mut self) -> Self {
+        self.port = std::option::Option::None;
+        self
+    }
+    /// Unsets `timeout`, as in a new builder.
+    pub fn reset_timeout(mut self) -> Self {
+        self.timeout = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(mut self) -> Self {
+        self.name = std::option::Option::None;
+        self.port = std::option::Option::None;
+        self.timeout = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Server`] from the fields set so far.
+    ///
+    /// Fails with [`ServerBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(self) -> std::result::Result<Server, ServerBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.name.is_none() {
+            __errors.push(ServerBuilderError::MissingName);
+        }
+        if let (std::option::Option::Some(name),) = (self.name,) {
+            let port = match self.port {
+                std::option::Option::Some(x) => x,
+                std::option::Option::None => 80,
+            };
+            let timeout = self.timeout;
+            if __errors.is_empty() {
+                std::result::Result::Ok(Server { name, port, timeout })
+            } else {
+                std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+        }
+    }
+    /// Builds a [`Server`] in a const context.
+    ///
+    /// Panics when a required field is not set, which fails the compilation when evaluated in a constant.
+    pub const fn build_const(self) -> Server {
+        let name = match self.name {
+            std::option::Option::Some(x) => x,
+            std::option::Option::None => std::panic!("`name` is required"),
+        };
+        let port = match self.port {
+            std::option::Option::Some(x) => x,
+            std::option::Option::None => 80,
+        };
+        let timeout = self.timeout;
+        Server { name, port, timeout }
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl ServerBuilderEdit) {
+        *self = f(std::mem::take(self));
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Server, ServerBuilderError> {
+        self.build()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Server, ServerBuilderError>
+    where
+        for<'__builder> ServerBuilder: std::clone::Clone,
+    {
+        std::clone::Clone::clone(self).build()
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.name.is_none() {
+            missing.push(std::format!("{}{}", path, "name"));
+        }
+    }
+}
+/// Closures editing a [`ServerBuilder`], as taken by the setters of outer builders holding it.
+pub trait ServerBuilderEdit: for<'__edit> std::ops::FnOnce(
+        ServerBuilder,
+    ) -> ServerBuilder {}
+impl<__F> ServerBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(ServerBuilder) -> ServerBuilder,
+{}
+impl std::convert::From<Server> for ServerBuilder {
+    fn from(value: Server) -> Self {
+        let Server { name, port, timeout } = value;
+        Self {
+            name: std::option::Option::Some(name),
+            port: std::option::Option::Some(port),
+            timeout,
+        }
+    }
+}
+impl Server {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> ServerBuilder
+    where
+        for<'__builder> Server: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`ServerBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ServerBuilderError {
+    /// `name` was not set.
+    MissingName,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ServerBuilderError>),
+}
+impl ServerBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ServerBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ServerBuilderError::MissingName => {
+                std::write!(f, "`{}` is required", field("name"))
+            }
+            ServerBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ServerBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ServerBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ServerBuilderError {}
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const, setter(strip_option))]
+pub struct Server {
+    name: &'static str,
+    #[builder(default = "80")]
+    port: u16,
+    timeout: Option<u32>,
+}
@@ -0,0 +1,192 @@
+use derive_builder::Builder;
+#[derive(Builder, Default)]
+#[builder(
+    name = "CmdBuilder",
+    vis = "pub(crate)",
+    derive(Debug, Clone),
+    build_fn(name = "finish"),
+    constructor(name = "new_builder"),
+    doc = "Assembles a [`Command`].",
+    default
+)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+}
+impl Command {
+    /// Returns a new [`CmdBuilder`].
+    pub(crate) fn new_builder() -> CmdBuilder {
+        std::default::Default::default()
+    }
+}
+///Assembles a [`Command`].
+#[derive(Debug, Clone)]
+pub(crate) struct CmdBuilder {
+    executable: std::option::Option<String>,
+    args: std::option::Option<Vec<String>>,
+}
+impl std::default::Default for CmdBuilder {
+    fn default() -> Self {
+        CmdBuilder {
+            executable: std::option::Option::None,
+            args: std::option::Option::None,
+        }
+    }
+}
+impl CmdBuilder {
+    /// Sets `executable`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub fn executable(&mut self, executable: String) -> &mut Self {
+        self.executable = std::option::Option::Some(executable);
+        self
+    }
+    /// Sets `args`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub fn args(&mut self, args: Vec<String>) -> &mut Self {
+        self.args = std::option::Option::Some(args);
+        self
+    }
+    /// Unsets `executable`, as in a new builder.
+    pub fn reset_executable(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self
+    }
+    /// Unsets `args`, as in a new builder.
+    pub fn reset_args(&mut self) -> &mut Self {
+        self.args = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self.args = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Command`] from the fields set so far.
+    ///
+    /// Fails with [`CmdBuilderError`] when a required field is not set or a value is rejected.
+    pub(crate) fn finish(&mut self) -> std::result::Result<Command, CmdBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl CmdBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Command, CmdBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Command, CmdBuilderError> {
+        let __default: Command = std::default::Default::default();
+        let executable = match self.executable.clone() {
+            std::option::Option::Some(x) => x,
+            std::option::Option::None => __default.executable,
+        };
+        let args = match self.args.clone() {
+            std::option::Option::Some(x) => x,
+            std::option::Option::None => __default.args,
+        };
+        std::result::Result::Ok(Command { executable, args })
+    }
+    #[doc(hidden)]
+    #[allow(unused_variables, clippy::ptr_arg)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {}
+}
+/// Closures editing a [`CmdBuilder`], as taken by the setters of outer builders holding it.
+pub(crate) trait CmdBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CmdBuilder,
+    ) -> &'__edit mut CmdBuilder {}
+impl<__F> CmdBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CmdBuilder,
+    ) -> &'__edit mut CmdBuilder,
+{}
+impl std::convert::From<Command> for CmdBuilder {
+    fn from(value: Command) -> Self {
+        let Command { executable, args } = value;
+        Self {
+            executable: std::option::Option::Some(executable),
+            args: std::option::Option::Some(args),
+        }
+    }
+}
+impl Command {
+    /// Returns a builder with every field set from `self`.
+    pub(crate) fn to_builder(&self) -> CmdBuilder
+    where
+        for<'__builder> Command: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`CmdBuilder::finish`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub(crate) enum CmdBuilderError {
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<CmdBuilderError>),
+}
+impl CmdBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            CmdBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            CmdBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            CmdBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            CmdBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for CmdBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for CmdBuilderError {}
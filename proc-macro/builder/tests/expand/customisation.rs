@@ -0,0 +1,16 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Default)]
+#[builder(
+    name = "CmdBuilder",
+    vis = "pub(crate)",
+    derive(Debug, Clone),
+    build_fn(name = "finish"),
+    constructor(name = "new_builder"),
+    doc = "Assembles a [`Command`].",
+    default
+)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+}
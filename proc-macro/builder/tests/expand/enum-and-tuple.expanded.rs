@@ -0,0 +1,658 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+pub struct Point(i32, #[builder(name = "y")] i32);
+impl Point {
+    /// Returns a new [`PointBuilder`].
+    pub fn builder() -> PointBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Point`], returned by [`Point::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`PointBuilderEdit`] closure.
+pub struct PointBuilder {
+    _0: std::option::Option<i32>,
+    y: std::option::Option<i32>,
+}
+impl std::default::Default for PointBuilder {
+    fn default() -> Self {
+        PointBuilder {
+            _0: std::option::Option::None,
+            y: std::option::Option::None,
+        }
+    }
+}
+impl PointBuilder {
+    /// Sets `_0`.
+    ///
+    /// This field is required.
+    pub fn _0(&mut self, _0: i32) -> &mut Self {
+        self._0 = std::option::Option::Some(_0);
+        self
+    }
+    /// Sets `y`.
+    ///
+    /// This field is required.
+    pub fn y(&mut self, y: i32) -> &mut Self {
+        self.y = std::option::Option::Some(y);
+        self
+    }
+    /// Unsets `_0`, as in a new builder.
+    pub fn reset_0(&mut self) -> &mut Self {
+        self._0 = std::option::Option::None;
+        self
+    }
+    /// Unsets `y`, as in a new builder.
+    pub fn reset_y(&mut self) -> &mut Self {
+        self.y = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self._0 = std::option::Option::None;
+        self.y = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Point`] from the fields set so far.
+    ///
+    /// Fails with [`PointBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Point, PointBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl PointBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Point, PointBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Point, PointBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self._0.is_none() {
+            __errors.push(PointBuilderError::Missing0);
+        }
+        if self.y.is_none() {
+            __errors.push(PointBuilderError::MissingY);
+        }
+        if let (std::option::Option::Some(_0), std::option::Option::Some(y))
+            = (self._0.clone(), self.y.clone()) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Point { 0: _0, 1: y })
+            } else {
+                std::result::Result::Err(PointBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(PointBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self._0.is_none() {
+            missing.push(std::format!("{}{}", path, "_0"));
+        }
+        if self.y.is_none() {
+            missing.push(std::format!("{}{}", path, "y"));
+        }
+    }
+}
+/// Closures editing a [`PointBuilder`], as taken by the setters of outer builders holding it.
+pub trait PointBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut PointBuilder,
+    ) -> &'__edit mut PointBuilder {}
+impl<__F> PointBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut PointBuilder,
+    ) -> &'__edit mut PointBuilder,
+{}
+impl std::convert::From<Point> for PointBuilder {
+    fn from(value: Point) -> Self {
+        let Point { 0: _0, 1: y } = value;
+        Self {
+            _0: std::option::Option::Some(_0),
+            y: std::option::Option::Some(y),
+        }
+    }
+}
+impl Point {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> PointBuilder
+    where
+        for<'__builder> Point: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`PointBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum PointBuilderError {
+    /// `_0` was not set.
+    Missing0,
+    /// `y` was not set.
+    MissingY,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<PointBuilderError>),
+}
+impl PointBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            PointBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            PointBuilderError::Missing0 => {
+                std::write!(f, "`{}` is required", field("_0"))
+            }
+            PointBuilderError::MissingY => std::write!(f, "`{}` is required", field("y")),
+            PointBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            PointBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            PointBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for PointBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for PointBuilderError {}
+#[derive(Builder)]
+pub enum Shape {
+    Circle { radius: f64 },
+    #[builder(name = "RectangleBuilder")]
+    Rect(f64, f64),
+}
+impl Shape {
+    /// Returns a new [`ShapeCircleBuilder`].
+    pub fn circle_builder() -> ShapeCircleBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Shape::Circle`], returned by [`Shape::circle_builder`].
+pub struct ShapeCircleBuilder {
+    radius: std::option::Option<f64>,
+}
+impl std::default::Default for ShapeCircleBuilder {
+    fn default() -> Self {
+        ShapeCircleBuilder {
+            radius: std::option::Option::None,
+        }
+    }
+}
+impl ShapeCircleBuilder {
+    /// Sets `radius`.
+    ///
+    /// This field is required.
+    pub fn radius(&mut self, radius: f64) -> &mut Self {
+        self.radius = std::option::Option::Some(radius);
+        self
+    }
+    /// Unsets `radius`, as in a new builder.
+    pub fn reset_radius(&mut self) -> &mut Self {
+        self.radius = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.radius = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Shape::Circle`] from the fields set so far.
+    ///
+    /// Fails with [`ShapeCircleBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Shape, ShapeCircleBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.radius.is_none() {
+            __errors.push(ShapeCircleBuilderError::MissingRadius);
+        }
+        if let (std::option::Option::Some(radius),) = (self.radius.clone(),) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Shape::Circle { radius })
+            } else {
+                std::result::Result::Err(ShapeCircleBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ShapeCircleBuilderError::from_errors(__errors))
+        }
+    }
+}
+/// Error returned by [`ShapeCircleBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ShapeCircleBuilderError {
+    /// `radius` was not set.
+    MissingRadius,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ShapeCircleBuilderError>),
+}
+impl ShapeCircleBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ShapeCircleBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ShapeCircleBuilderError::MissingRadius => {
+                std::write!(f, "`{}` is required", field("radius"))
+            }
+            ShapeCircleBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ShapeCircleBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ShapeCircleBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ShapeCircleBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ShapeCircleBuilderError {}
+impl Shape {
+    /// Returns a new [`RectangleBuilder`].
+    pub fn rect_builder() -> RectangleBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Shape::Rect`], returned by [`Shape::rect_builder`].
+pub struct RectangleBuilder {
+    _0: std::option::Option<f64>,
+    _1: std::option::Option<f64>,
+}
+impl std::default::Default for RectangleBuilder {
+    fn default() -> Self {
+        RectangleBuilder {
+            _0: std::option::Option::None,
+            _1: std::option::Option::None,
+        }
+    }
+}
+impl RectangleBuilder {
+    /// Sets `_0`.
+    ///
+    /// This field is required.
+    pub fn _0(&mut self, _0: f64) -> &mut Self {
+        self._0 = std::option::Option::Some(_0);
+        self
+    }
+    /// Sets `_1`.
+    ///
+    /// This field is required.
+    pub fn _1(&mut self, _1: f64) -> &mut Self {
+        self._1 = std::option::Option::Some(_1);
+        self
+    }
+    /// Unsets `_0`, as in a new builder.
+    pub fn reset_0(&mut self) -> &mut Self {
+        self._0 = std::option::Option::None;
+        self
+    }
+    /// Unsets `_1`, as in a new builder.
+    pub fn reset_1(&mut self) -> &mut Self {
+        self._1 = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self._0 = std::option::Option::None;
+        self._1 = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Shape::Rect`] from the fields set so far.
+    ///
+    /// Fails with [`RectangleBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Shape, RectangleBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self._0.is_none() {
+            __errors.push(RectangleBuilderError::Missing0);
+        }
+        if self._1.is_none() {
+            __errors.push(RectangleBuilderError::Missing1);
+        }
+        if let (std::option::Option::Some(_0), std::option::Option::Some(_1))
+            = (self._0.clone(), self._1.clone()) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Shape::Rect { 0: _0, 1: _1 })
+            } else {
+                std::result::Result::Err(RectangleBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(RectangleBuilderError::from_errors(__errors))
+        }
+    }
+}
+/// Error returned by [`RectangleBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum RectangleBuilderError {
+    /// `_0` was not set.
+    Missing0,
+    /// `_1` was not set.
+    Missing1,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<RectangleBuilderError>),
+}
+impl RectangleBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            RectangleBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            RectangleBuilderError::Missing0 => {
+                std::write!(f, "`{}` is required", field("_0"))
+            }
+            RectangleBuilderError::Missing1 => {
+                std::write!(f, "`{}` is required", field("_1"))
+            }
+            RectangleBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            RectangleBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            RectangleBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for RectangleBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for RectangleBuilderError {}
+#[derive(Builder)]
+pub struct Wrapper<T: Clone> {
+    value: T,
+}
+impl<T: Clone> Wrapper<T> {
+    /// Returns a new [`WrapperBuilder`].
+    pub fn builder() -> WrapperBuilder<T> {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Wrapper`], returned by [`Wrapper::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`WrapperBuilderEdit`] closure.
+pub struct WrapperBuilder<T: Clone> {
+    value: std::option::Option<T>,
+    __marker: std::marker::PhantomData<fn() -> Wrapper<T>>,
+}
+impl<T: Clone> std::default::Default for WrapperBuilder<T> {
+    fn default() -> Self {
+        WrapperBuilder {
+            value: std::option::Option::None,
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone> WrapperBuilder<T> {
+    /// Sets `value`.
+    ///
+    /// This field is required.
+    pub fn value(&mut self, value: T) -> &mut Self {
+        self.value = std::option::Option::Some(value);
+        self
+    }
+    /// Unsets `value`, as in a new builder.
+    pub fn reset_value(&mut self) -> &mut Self {
+        self.value = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.value = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Wrapper`] from the fields set so far.
+    ///
+    /// Fails with [`WrapperBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Wrapper<T>, WrapperBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl WrapperBuilderEdit<T>) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Wrapper<T>, WrapperBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Wrapper<T>, WrapperBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.value.is_none() {
+            __errors.push(WrapperBuilderError::MissingValue);
+        }
+        if let (std::option::Option::Some(value),) = (self.value.clone(),) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Wrapper { value })
+            } else {
+                std::result::Result::Err(WrapperBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(WrapperBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.value.is_none() {
+            missing.push(std::format!("{}{}", path, "value"));
+        }
+    }
+}
+/// Closures editing a [`WrapperBuilder`], as taken by the setters of outer builders holding it.
+pub trait WrapperBuilderEdit<
+    T: Clone,
+>: for<'__edit> std::ops::FnOnce(
+        &'__edit mut WrapperBuilder<T>,
+    ) -> &'__edit mut WrapperBuilder<T> {}
+impl<T: Clone, __F> WrapperBuilderEdit<T> for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut WrapperBuilder<T>,
+    ) -> &'__edit mut WrapperBuilder<T>,
+{}
+impl<T: Clone> std::convert::From<Wrapper<T>> for WrapperBuilder<T> {
+    fn from(value: Wrapper<T>) -> Self {
+        let Wrapper { value } = value;
+        Self {
+            value: std::option::Option::Some(value),
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone> Wrapper<T> {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> WrapperBuilder<T>
+    where
+        for<'__builder> Wrapper<T>: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`WrapperBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum WrapperBuilderError {
+    /// `value` was not set.
+    MissingValue,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<WrapperBuilderError>),
+}
+impl WrapperBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            WrapperBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            WrapperBuilderError::MissingValue => {
+                std::write!(f, "`{}` is required", field("value"))
+            }
+            WrapperBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            WrapperBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            WrapperBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for WrapperBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for WrapperBuilderError {}
@@ -0,0 +1,16 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Point(i32, #[builder(name = "y")] i32);
+
+#[derive(Builder)]
+pub enum Shape {
+    Circle { radius: f64 },
+    #[builder(name = "RectangleBuilder")]
+    Rect(f64, f64),
+}
+
+#[derive(Builder)]
+pub struct Wrapper<T: Clone> {
+    value: T,
+}
@@ -0,0 +1,379 @@
+use derive_builder::Builder;
+use std::collections::HashMap;
+type MaybeUser = Option<String>;
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(into, name = "program"))]
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env", default = "HashMap::new()")]
+    envs: HashMap<String, String>,
+    #[builder(default)]
+    quiet: bool,
+    #[builder(setter(strip_option))]
+    current_dir: Option<String>,
+    #[builder(optional)]
+    user: MaybeUser,
+    #[builder(required)]
+    group: Option<String>,
+    #[builder(skip)]
+    pid: u32,
+    #[builder(compute = "args.len()")]
+    argc: usize,
+}
+impl Command {
+    /// Returns a new [`CommandBuilder`].
+    pub fn builder() -> CommandBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Command`], returned by [`Command::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`CommandBuilderEdit`] closure.
+pub struct CommandBuilder {
+    executable: std::option::Option<String>,
+    args: Vec<String>,
+    envs: HashMap<String, String>,
+    quiet: std::option::Option<bool>,
+    current_dir: std::option::Option<String>,
+    user: std::option::Option<<MaybeUser as std::iter::IntoIterator>::Item>,
+    group: std::option::Option<Option<String>>,
+}
+impl std::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: std::option::Option::None,
+            args: std::default::Default::default(),
+            envs: std::default::Default::default(),
+            quiet: std::option::Option::None,
+            current_dir: std::option::Option::None,
+            user: std::option::Option::None,
+            group: std::option::Option::None,
+        }
+    }
+}
+impl CommandBuilder {
+    /// Sets `executable`.
+    ///
+    /// This field is required.
+    pub fn program(&mut self, executable: impl std::convert::Into<String>) -> &mut Self {
+        self
+            .executable = std::option::Option::Some(
+            std::convert::Into::into(executable),
+        );
+        self
+    }
+    /// Sets `args`.
+    ///
+    /// This field is optional.
+    pub fn args(&mut self, args: Vec<String>) -> &mut Self {
+        self.args = args;
+        self
+    }
+    /// Adds an item to `args`.
+    pub fn arg(&mut self, arg: String) -> &mut Self {
+        std::iter::Extend::extend(&mut self.args, std::iter::once(arg));
+        self
+    }
+    /// Adds every item of an iterator to `args`.
+    pub fn extend_args(
+        &mut self,
+        args: impl std::iter::IntoIterator<Item = String>,
+    ) -> &mut Self {
+        std::iter::Extend::extend(&mut self.args, args);
+        self
+    }
+    /// Sets `envs`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub fn envs(&mut self, envs: HashMap<String, String>) -> &mut Self {
+        self.envs = envs;
+        self
+    }
+    /// Adds an item to `envs`.
+    pub fn env(&mut self, key: String, value: String) -> &mut Self {
+        std::iter::Extend::extend(&mut self.envs, std::iter::once((key, value)));
+        self
+    }
+    /// Adds every item of an iterator to `envs`.
+    pub fn extend_envs(
+        &mut self,
+        envs: impl std::iter::IntoIterator<Item = (String, String)>,
+    ) -> &mut Self {
+        std::iter::Extend::extend(&mut self.envs, envs);
+        self
+    }
+    /// Sets `quiet`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub fn quiet(&mut self, quiet: bool) -> &mut Self {
+        self.quiet = std::option::Option::Some(quiet);
+        self
+    }
+    /// Sets `current_dir`.
+    ///
+    /// This field is optional.
+    pub fn current_dir(&mut self, current_dir: String) -> &mut Self {
+        self.current_dir = std::option::Option::Some(current_dir);
+        self
+    }
+    /// Sets `current_dir` to an `Option`, `None` unsets it.
+    pub fn current_dir_opt(
+        &mut self,
+        current_dir: std::option::Option<String>,
+    ) -> &mut Self {
+        self.current_dir = current_dir;
+        self
+    }
+    /// Sets `user`.
+    ///
+    /// This field is optional.
+    pub fn user(
+        &mut self,
+        user: <MaybeUser as std::iter::IntoIterator>::Item,
+    ) -> &mut Self {
+        self.user = std::option::Option::Some(user);
+        self
+    }
+    /// Sets `group`.
+    ///
+    /// This field is required.
+    pub fn group(&mut self, group: Option<String>) -> &mut Self {
+        self.group = std::option::Option::Some(group);
+        self
+    }
+    /// Unsets `executable`, as in a new builder.
+    pub fn reset_program(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self
+    }
+    /// Unsets `args`, as in a new builder.
+    pub fn reset_args(&mut self) -> &mut Self {
+        self.args = std::default::Default::default();
+        self
+    }
+    /// Unsets `envs`, as in a new builder.
+    pub fn reset_envs(&mut self) -> &mut Self {
+        self.envs = std::default::Default::default();
+        self
+    }
+    /// Unsets `quiet`, as in a new builder.
+    pub fn reset_quiet(&mut self) -> &mut Self {
+        self.quiet = std::option::Option::None;
+        self
+    }
+    /// Unsets `current_dir`, as in a new builder.
+    pub fn reset_current_dir(&mut self) -> &mut Self {
+        self.current_dir = std::option::Option::None;
+        self
+    }
+    /// Unsets `user`, as in a new builder.
+    pub fn reset_user(&mut self) -> &mut Self {
+        self.user = std::option::Option::None;
+        self
+    }
+    /// Unsets `group`, as in a new builder.
+    pub fn reset_group(&mut self) -> &mut Self {
+        self.group = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.executable = std::option::Option::None;
+        self.args = std::default::Default::default();
+        self.envs = std::default::Default::default();
+        self.quiet = std::option::Option::None;
+        self.current_dir = std::option::Option::None;
+        self.user = std::option::Option::None;
+        self.group = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Command`] from the fields set so far.
+    ///
+    /// Fails with [`CommandBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Command, CommandBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl CommandBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Command, CommandBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Command, CommandBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.executable.is_none() {
+            __errors.push(CommandBuilderError::MissingExecutable);
+        }
+        if self.group.is_none() {
+            __errors.push(CommandBuilderError::MissingGroup);
+        }
+        if let (std::option::Option::Some(executable), std::option::Option::Some(group))
+            = (self.executable.clone(), self.group.clone()) {
+            let args = self.args.clone();
+            let envs = {
+                let mut envs: HashMap<String, String> = HashMap::new();
+                std::iter::Extend::extend(&mut envs, self.envs.clone());
+                envs
+            };
+            let quiet = match self.quiet.clone() {
+                std::option::Option::Some(x) => x,
+                std::option::Option::None => std::default::Default::default(),
+            };
+            let current_dir = self.current_dir.clone();
+            let user = self.user.clone();
+            let pid = std::default::Default::default();
+            let argc = args.len();
+            if __errors.is_empty() {
+                std::result::Result::Ok(Command {
+                    executable,
+                    args,
+                    envs,
+                    quiet,
+                    current_dir,
+                    user,
+                    group,
+                    pid,
+                    argc,
+                })
+            } else {
+                std::result::Result::Err(CommandBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(CommandBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.executable.is_none() {
+            missing.push(std::format!("{}{}", path, "executable"));
+        }
+        if self.group.is_none() {
+            missing.push(std::format!("{}{}", path, "group"));
+        }
+    }
+}
+/// Closures editing a [`CommandBuilder`], as taken by the setters of outer builders holding it.
+pub trait CommandBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CommandBuilder,
+    ) -> &'__edit mut CommandBuilder {}
+impl<__F> CommandBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut CommandBuilder,
+    ) -> &'__edit mut CommandBuilder,
+{}
+impl std::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        let Command {
+            executable,
+            args,
+            envs,
+            quiet,
+            current_dir,
+            user,
+            group,
+            pid: _,
+            argc: _,
+        } = value;
+        Self {
+            executable: std::option::Option::Some(executable),
+            args,
+            envs,
+            quiet: std::option::Option::Some(quiet),
+            current_dir,
+            user,
+            group: std::option::Option::Some(group),
+        }
+    }
+}
+impl Command {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__builder> Command: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`CommandBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum CommandBuilderError {
+    /// `executable` was not set.
+    MissingExecutable,
+    /// `group` was not set.
+    MissingGroup,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<CommandBuilderError>),
+}
+impl CommandBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            CommandBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            CommandBuilderError::MissingExecutable => {
+                std::write!(f, "`{}` is required", field("executable"))
+            }
+            CommandBuilderError::MissingGroup => {
+                std::write!(f, "`{}` is required", field("group"))
+            }
+            CommandBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            CommandBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            CommandBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for CommandBuilderError {}
@@ -0,0 +1,26 @@
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+type MaybeUser = Option<String>;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(into, name = "program"))]
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env", default = "HashMap::new()")]
+    envs: HashMap<String, String>,
+    #[builder(default)]
+    quiet: bool,
+    #[builder(setter(strip_option))]
+    current_dir: Option<String>,
+    #[builder(optional)]
+    user: MaybeUser,
+    #[builder(required)]
+    group: Option<String>,
+    #[builder(skip)]
+    pid: u32,
+    #[builder(compute = "args.len()")]
+    argc: usize,
+}
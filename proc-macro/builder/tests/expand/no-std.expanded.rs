@@ -0,0 +1,250 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(no_std)]
+pub struct Frame {
+    id: u32,
+    #[builder(each = "byte")]
+    data: Vec<u8>,
+    label: Option<String>,
+    #[builder(default = "std::sync::Arc::new(0)")]
+    shared: std::sync::Arc<u8>,
+}
+impl Frame {
+    /// Returns a new [`FrameBuilder`].
+    pub fn builder() -> FrameBuilder {
+        core::default::Default::default()
+    }
+}
+/// Builder for [`Frame`], returned by [`Frame::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`FrameBuilderEdit`] closure.
+pub struct FrameBuilder {
+    id: core::option::Option<u32>,
+    data: Vec<u8>,
+    label: core::option::Option<String>,
+    shared: core::option::Option<std::sync::Arc<u8>>,
+}
+impl core::default::Default for FrameBuilder {
+    fn default() -> Self {
+        FrameBuilder {
+            id: core::option::Option::None,
+            data: core::default::Default::default(),
+            label: core::option::Option::None,
+            shared: core::option::Option::None,
+        }
+    }
+}
+impl FrameBuilder {
+    /// Sets `id`.
+    ///
+    /// This field is required.
+    pub fn id(&mut self, id: u32) -> &mut Self {
+        self.id = core::option::Option::Some(id);
+        self
+    }
+    /// Sets `data`.
+    ///
+    /// This field is optional.
+    pub fn data(&mut self, data: Vec<u8>) -> &mut Self {
+        self.data = data;
+        self
+    }
+    /// Adds an item to `data`.
+    pub fn byte(&mut self, byte: u8) -> &mut Self {
+        core::iter::Extend::extend(&mut self.data, core::iter::once(byte));
+        self
+    }
+    /// Adds every item of an iterator to `data`.
+    pub fn extend_data(
+        &mut self,
+        data: impl core::iter::IntoIterator<Item = u8>,
+    ) -> &mut Self {
+        core::iter::Extend::extend(&mut self.data, data);
+        self
+    }
+    /// Sets `label`.
+    ///
+    /// This field is optional.
+    pub fn label(&mut self, label: String) -> &mut Self {
+        self.label = core::option::Option::Some(label);
+        self
+    }
+    /// Sets `shared`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub fn shared(&mut self, shared: std::sync::Arc<u8>) -> &mut Self {
+        self.shared = core::option::Option::Some(shared);
+        self
+    }
+    /// Unsets `id`, as in a new builder.
+    pub fn reset_id(&mut self) -> &mut Self {
+        self.id = core::option::Option::None;
+        self
+    }
+    /// Unsets `data`, as in a new builder.
+    pub fn reset_data(&mut self) -> &mut Self {
+        self.data = core::default::Default::default();
+        self
+    }
+    /// Unsets `label`, as in a new builder.
+    pub fn reset_label(&mut self) -> &mut Self {
+        self.label = core::option::Option::None;
+        self
+    }
+    /// Unsets `shared`, as in a new builder.
+    pub fn reset_shared(&mut self) -> &mut Self {
+        self.shared = core::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.id = core::option::Option::None;
+        self.data = core::default::Default::default();
+        self.label = core::option::Option::None;
+        self.shared = core::option::Option::None;
+        self
+    }
+    /// Builds a [`Frame`] from the fields set so far.
+    ///
+    /// Fails with [`FrameBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> core::result::Result<Frame, FrameBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl FrameBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> core::result::Result<Frame, FrameBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> core::result::Result<Frame, FrameBuilderError> {
+        let mut __errors = alloc::vec::Vec::new();
+        if self.id.is_none() {
+            __errors.push(FrameBuilderError::MissingId);
+        }
+        if let (core::option::Option::Some(id),) = (self.id.clone(),) {
+            let data = self.data.clone();
+            let label = self.label.clone();
+            let shared = match self.shared.clone() {
+                core::option::Option::Some(x) => x,
+                core::option::Option::None => std::sync::Arc::new(0),
+            };
+            if __errors.is_empty() {
+                core::result::Result::Ok(Frame { id, data, label, shared })
+            } else {
+                core::result::Result::Err(FrameBuilderError::from_errors(__errors))
+            }
+        } else {
+            core::result::Result::Err(FrameBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut alloc::vec::Vec<alloc::string::String>,
+    ) {
+        if self.id.is_none() {
+            missing.push(alloc::format!("{}{}", path, "id"));
+        }
+    }
+}
+/// Closures editing a [`FrameBuilder`], as taken by the setters of outer builders holding it.
+pub trait FrameBuilderEdit: for<'__edit> core::ops::FnOnce(
+        &'__edit mut FrameBuilder,
+    ) -> &'__edit mut FrameBuilder {}
+impl<__F> FrameBuilderEdit for __F
+where
+    __F: for<'__edit> core::ops::FnOnce(
+        &'__edit mut FrameBuilder,
+    ) -> &'__edit mut FrameBuilder,
+{}
+impl core::convert::From<Frame> for FrameBuilder {
+    fn from(value: Frame) -> Self {
+        let Frame { id, data, label, shared } = value;
+        Self {
+            id: core::option::Option::Some(id),
+            data,
+            label,
+            shared: core::option::Option::Some(shared),
+        }
+    }
+}
+impl Frame {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> FrameBuilder
+    where
+        for<'__builder> Frame: core::clone::Clone,
+    {
+        core::convert::From::from(core::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`FrameBuilder::build`].
+#[derive(core::fmt::Debug, core::clone::Clone, core::cmp::PartialEq, core::cmp::Eq)]
+pub enum FrameBuilderError {
+    /// `id` was not set.
+    MissingId,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: core::option::Option<&'static str>,
+        /// The message of the validator.
+        message: alloc::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(alloc::vec::Vec<FrameBuilderError>),
+}
+impl FrameBuilderError {
+    fn from_errors(mut errors: alloc::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            FrameBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: core::option::Option<&str>,
+        f: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        let field = |name: &str| match path {
+            core::option::Option::Some(path) => alloc::format!("{}.{}", path, name),
+            core::option::Option::None => alloc::string::ToString::to_string(name),
+        };
+        match self {
+            FrameBuilderError::MissingId => {
+                core::write!(f, "`{}` is required", field("id"))
+            }
+            FrameBuilderError::Validation {
+                field: core::option::Option::Some(name),
+                message,
+            } => core::write!(f, "invalid `{}`: {}", field(name), message),
+            FrameBuilderError::Validation { message, .. } => {
+                match path {
+                    core::option::Option::Some(path) => {
+                        core::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    core::option::Option::None => f.write_str(message),
+                }
+            }
+            FrameBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                core::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl core::fmt::Display for FrameBuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.__fmt_path(core::option::Option::None, f)
+    }
+}
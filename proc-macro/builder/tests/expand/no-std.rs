@@ -0,0 +1,12 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(no_std)]
+pub struct Frame {
+    id: u32,
+    #[builder(each = "byte")]
+    data: Vec<u8>,
+    label: Option<String>,
+    #[builder(default = "std::sync::Arc::new(0)")]
+    shared: std::sync::Arc<u8>,
+}
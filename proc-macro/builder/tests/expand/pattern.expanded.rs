@@ -0,0 +1,396 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Owned {
+    name: String,
+    port: Option<u16>,
+}
+impl Owned {
+    /// Returns a new [`OwnedBuilder`].
+    pub fn builder() -> OwnedBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Owned`], returned by [`Owned::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`OwnedBuilderEdit`] closure.
+pub struct OwnedBuilder {
+    name: std::option::Option<String>,
+    port: std::option::Option<u16>,
+}
+impl std::default::Default for OwnedBuilder {
+    fn default() -> Self {
+        OwnedBuilder {
+            name: std::option::Option::None,
+            port: std::option::Option::None,
+        }
+    }
+}
+impl OwnedBuilder {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub fn name(mut self, name: String) -> Self {
+        self.name = std::option::Option::Some(name);
+        self
+    }
+    /// Sets `port`.
+    ///
+    /// This field is optional.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = std::option::Option::Some(port);
+        self
+    }
+    /// Unsets `name`, as in a new builder.
+    pub fn reset_name(mut self) -> Self {
+        self.name = std::option::Option::None;
+        self
+    }
+    /// Unsets `port`, as in a new builder.
+    pub fn reset_port(mut self) -> Self {
+        self.port = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(mut self) -> Self {
+        self.name = std::option::Option::None;
+        self.port = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Owned`] from the fields set so far.
+    ///
+    /// Fails with [`OwnedBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(self) -> std::result::Result<Owned, OwnedBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.name.is_none() {
+            __errors.push(OwnedBuilderError::MissingName);
+        }
+        if let (std::option::Option::Some(name),) = (self.name,) {
+            let port = self.port;
+            if __errors.is_empty() {
+                std::result::Result::Ok(Owned { name, port })
+            } else {
+                std::result::Result::Err(OwnedBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(OwnedBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl OwnedBuilderEdit) {
+        *self = f(std::mem::take(self));
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Owned, OwnedBuilderError> {
+        self.build()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Owned, OwnedBuilderError>
+    where
+        for<'__builder> OwnedBuilder: std::clone::Clone,
+    {
+        std::clone::Clone::clone(self).build()
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.name.is_none() {
+            missing.push(std::format!("{}{}", path, "name"));
+        }
+    }
+}
+/// Closures editing a [`OwnedBuilder`], as taken by the setters of outer builders holding it.
+pub trait OwnedBuilderEdit: for<'__edit> std::ops::FnOnce(OwnedBuilder) -> OwnedBuilder {}
+impl<__F> OwnedBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(OwnedBuilder) -> OwnedBuilder,
+{}
+impl std::convert::From<Owned> for OwnedBuilder {
+    fn from(value: Owned) -> Self {
+        let Owned { name, port } = value;
+        Self {
+            name: std::option::Option::Some(name),
+            port,
+        }
+    }
+}
+impl Owned {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> OwnedBuilder
+    where
+        for<'__builder> Owned: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`OwnedBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum OwnedBuilderError {
+    /// `name` was not set.
+    MissingName,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<OwnedBuilderError>),
+}
+impl OwnedBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            OwnedBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            OwnedBuilderError::MissingName => {
+                std::write!(f, "`{}` is required", field("name"))
+            }
+            OwnedBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            OwnedBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            OwnedBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for OwnedBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for OwnedBuilderError {}
+#[derive(Builder)]
+#[builder(pattern = "immutable")]
+pub struct Immutable {
+    name: String,
+    port: Option<u16>,
+}
+impl Immutable {
+    /// Returns a new [`ImmutableBuilder`].
+    pub fn builder() -> ImmutableBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Immutable`], returned by [`Immutable::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`ImmutableBuilderEdit`] closure.
+#[derive(std::clone::Clone)]
+pub struct ImmutableBuilder {
+    name: std::option::Option<String>,
+    port: std::option::Option<u16>,
+}
+impl std::default::Default for ImmutableBuilder {
+    fn default() -> Self {
+        ImmutableBuilder {
+            name: std::option::Option::None,
+            port: std::option::Option::None,
+        }
+    }
+}
+impl ImmutableBuilder {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub fn name(&self, name: String) -> Self {
+        let mut __builder = std::clone::Clone::clone(self);
+        __builder.name = std::option::Option::Some(name);
+        __builder
+    }
+    /// Sets `port`.
+    ///
+    /// This field is optional.
+    pub fn port(&self, port: u16) -> Self {
+        let mut __builder = std::clone::Clone::clone(self);
+        __builder.port = std::option::Option::Some(port);
+        __builder
+    }
+    /// Unsets `name`, as in a new builder.
+    pub fn reset_name(&self) -> Self {
+        let mut __builder = std::clone::Clone::clone(self);
+        __builder.name = std::option::Option::None;
+        __builder
+    }
+    /// Unsets `port`, as in a new builder.
+    pub fn reset_port(&self) -> Self {
+        let mut __builder = std::clone::Clone::clone(self);
+        __builder.port = std::option::Option::None;
+        __builder
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&self) -> Self {
+        let mut __builder = std::clone::Clone::clone(self);
+        __builder.name = std::option::Option::None;
+        __builder.port = std::option::Option::None;
+        __builder
+    }
+    /// Builds a [`Immutable`] from the fields set so far.
+    ///
+    /// Fails with [`ImmutableBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&self) -> std::result::Result<Immutable, ImmutableBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl ImmutableBuilderEdit) {
+        *self = f(std::mem::take(self));
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Immutable, ImmutableBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Immutable, ImmutableBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.name.is_none() {
+            __errors.push(ImmutableBuilderError::MissingName);
+        }
+        if let (std::option::Option::Some(name),) = (self.name.clone(),) {
+            let port = self.port.clone();
+            if __errors.is_empty() {
+                std::result::Result::Ok(Immutable { name, port })
+            } else {
+                std::result::Result::Err(ImmutableBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ImmutableBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.name.is_none() {
+            missing.push(std::format!("{}{}", path, "name"));
+        }
+    }
+}
+/// Closures editing a [`ImmutableBuilder`], as taken by the setters of outer builders holding it.
+pub trait ImmutableBuilderEdit: for<'__edit> std::ops::FnOnce(
+        ImmutableBuilder,
+    ) -> ImmutableBuilder {}
+impl<__F> ImmutableBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(ImmutableBuilder) -> ImmutableBuilder,
+{}
+impl std::convert::From<Immutable> for ImmutableBuilder {
+    fn from(value: Immutable) -> Self {
+        let Immutable { name, port } = value;
+        Self {
+            name: std::option::Option::Some(name),
+            port,
+        }
+    }
+}
+impl Immutable {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> ImmutableBuilder
+    where
+        for<'__builder> Immutable: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`ImmutableBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ImmutableBuilderError {
+    /// `name` was not set.
+    MissingName,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ImmutableBuilderError>),
+}
+impl ImmutableBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ImmutableBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ImmutableBuilderError::MissingName => {
+                std::write!(f, "`{}` is required", field("name"))
+            }
+            ImmutableBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ImmutableBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ImmutableBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ImmutableBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ImmutableBuilderError {}
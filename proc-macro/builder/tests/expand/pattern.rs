@@ -0,0 +1,15 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Owned {
+    name: String,
+    port: Option<u16>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "immutable")]
+pub struct Immutable {
+    name: String,
+    port: Option<u16>,
+}
@@ -0,0 +1,116 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(required_ctor)]
+pub struct Command {
+    executable: String,
+    #[builder(setter(into))]
+    cwd: String,
+    timeout: Option<u32>,
+}
+impl Command {
+    /// Returns a new [`CommandBuilder`] with the required fields set, in declaration order.
+    pub fn builder(
+        executable: String,
+        cwd: impl std::convert::Into<String>,
+    ) -> CommandBuilder<(String,), (String,)> {
+        CommandBuilder {
+            executable: (executable,),
+            cwd: (std::convert::Into::into(cwd),),
+            timeout: std::option::Option::None,
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+/// Builder for [`Command`], returned by [`Command::builder`].
+pub struct CommandBuilder<__Executable, __Cwd> {
+    executable: __Executable,
+    cwd: __Cwd,
+    timeout: std::option::Option<u32>,
+    __marker: std::marker::PhantomData<fn() -> Command>,
+}
+impl std::default::Default for CommandBuilder<(), ()> {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: (),
+            cwd: (),
+            timeout: std::option::Option::None,
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<__Executable, __Cwd> CommandBuilder<__Executable, __Cwd> {
+    /// Sets `executable`.
+    ///
+    /// This field is required.
+    pub fn executable(self, executable: String) -> CommandBuilder<(String,), __Cwd> {
+        CommandBuilder {
+            executable: (executable,),
+            cwd: self.cwd,
+            timeout: self.timeout,
+            __marker: std::marker::PhantomData,
+        }
+    }
+    /// Sets `cwd`.
+    ///
+    /// This field is required.
+    pub fn cwd(
+        self,
+        cwd: impl std::convert::Into<String>,
+    ) -> CommandBuilder<__Executable, (String,)> {
+        CommandBuilder {
+            executable: self.executable,
+            cwd: (std::convert::Into::into(cwd),),
+            timeout: self.timeout,
+            __marker: std::marker::PhantomData,
+        }
+    }
+    /// Sets `timeout`.
+    ///
+    /// This field is optional.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = std::option::Option::Some(timeout);
+        self
+    }
+    /// Unsets `timeout`, as in a new builder.
+    pub fn reset_timeout(mut self) -> Self {
+        self.timeout = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(self) -> CommandBuilder<(), ()> {
+        std::default::Default::default()
+    }
+}
+impl CommandBuilder<(String,), (String,)> {
+    /// Builds a [`Command`] from the fields set so far.
+    pub fn build(self) -> Command {
+        let executable = self.executable.0;
+        let cwd = self.cwd.0;
+        let timeout = self.timeout;
+        Command {
+            executable,
+            cwd,
+            timeout,
+        }
+    }
+}
+impl std::convert::From<Command> for CommandBuilder<(String,), (String,)> {
+    fn from(value: Command) -> Self {
+        let Command { executable, cwd, timeout } = value;
+        Self {
+            executable: (executable,),
+            cwd: (cwd,),
+            timeout,
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+impl Command {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> CommandBuilder<(String,), (String,)>
+    where
+        for<'__builder> Command: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(required_ctor)]
+pub struct Command {
+    executable: String,
+    #[builder(setter(into))]
+    cwd: String,
+    timeout: Option<u32>,
+}
@@ -0,0 +1,454 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(serde, pattern = "owned")]
+pub struct Limit {
+    #[builder(field_attr(serde(rename = "maximum")))]
+    max: u16,
+}
+impl Limit {
+    /// Returns a new [`LimitBuilder`].
+    pub fn builder() -> LimitBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Limit`], returned by [`Limit::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`LimitBuilderEdit`] closure.
+#[derive(::serde::Deserialize)]
+#[serde(default)]
+pub struct LimitBuilder {
+    #[serde(rename = "maximum")]
+    max: std::option::Option<u16>,
+}
+impl std::default::Default for LimitBuilder {
+    fn default() -> Self {
+        LimitBuilder {
+            max: std::option::Option::None,
+        }
+    }
+}
+impl LimitBuilder {
+    /// Sets `max`.
+    ///
+    /// This field is required.
+    pub fn max(mut self, max: u16) -> Self {
+        self.max = std::option::Option::Some(max);
+        self
+    }
+    /// Unsets `max`, as in a new builder.
+    pub fn reset_max(mut self) -> Self {
+        self.max = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(mut self) -> Self {
+        self.max = std::option::Option::None;
+        self
+    }
+    /// Sets every field that is set in `other`, appending the items of its collections.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.__merge(other);
+        self
+    }
+    #[doc(hidden)]
+    pub fn __merge(&mut self, other: Self) {
+        if other.max.is_some() {
+            self.max = other.max;
+        }
+    }
+    /// Builds a [`Limit`] from the fields set so far.
+    ///
+    /// Fails with [`LimitBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(self) -> std::result::Result<Limit, LimitBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.max.is_none() {
+            __errors.push(LimitBuilderError::MissingMax);
+        }
+        if let (std::option::Option::Some(max),) = (self.max,) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Limit { max })
+            } else {
+                std::result::Result::Err(LimitBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(LimitBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl LimitBuilderEdit) {
+        *self = f(std::mem::take(self));
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Limit, LimitBuilderError> {
+        self.build()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Limit, LimitBuilderError>
+    where
+        for<'__builder> LimitBuilder: std::clone::Clone,
+    {
+        std::clone::Clone::clone(self).build()
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.max.is_none() {
+            missing.push(std::format!("{}{}", path, "max"));
+        }
+    }
+}
+/// Closures editing a [`LimitBuilder`], as taken by the setters of outer builders holding it.
+pub trait LimitBuilderEdit: for<'__edit> std::ops::FnOnce(LimitBuilder) -> LimitBuilder {}
+impl<__F> LimitBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(LimitBuilder) -> LimitBuilder,
+{}
+impl std::convert::From<Limit> for LimitBuilder {
+    fn from(value: Limit) -> Self {
+        let Limit { max } = value;
+        Self {
+            max: std::option::Option::Some(max),
+        }
+    }
+}
+impl Limit {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> LimitBuilder
+    where
+        for<'__builder> Limit: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`LimitBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum LimitBuilderError {
+    /// `max` was not set.
+    MissingMax,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<LimitBuilderError>),
+}
+impl LimitBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            LimitBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            LimitBuilderError::MissingMax => {
+                std::write!(f, "`{}` is required", field("max"))
+            }
+            LimitBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            LimitBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            LimitBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for LimitBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for LimitBuilderError {}
+#[derive(Builder)]
+#[builder(serde)]
+pub struct Config {
+    name: String,
+    #[builder(each = "host")]
+    hosts: Vec<String>,
+    #[builder(sub_builder)]
+    limit: Limit,
+}
+impl Config {
+    /// Returns a new [`ConfigBuilder`].
+    pub fn builder() -> ConfigBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Config`], returned by [`Config::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`ConfigBuilderEdit`] closure.
+#[derive(::serde::Deserialize)]
+#[serde(default)]
+pub struct ConfigBuilder {
+    name: std::option::Option<String>,
+    hosts: Vec<String>,
+    limit: LimitBuilder,
+}
+impl std::default::Default for ConfigBuilder {
+    fn default() -> Self {
+        ConfigBuilder {
+            name: std::option::Option::None,
+            hosts: std::default::Default::default(),
+            limit: std::default::Default::default(),
+        }
+    }
+}
+impl ConfigBuilder {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = std::option::Option::Some(name);
+        self
+    }
+    /// Sets `hosts`.
+    ///
+    /// This field is optional.
+    pub fn hosts(&mut self, hosts: Vec<String>) -> &mut Self {
+        self.hosts = hosts;
+        self
+    }
+    /// Adds an item to `hosts`.
+    pub fn host(&mut self, host: String) -> &mut Self {
+        std::iter::Extend::extend(&mut self.hosts, std::iter::once(host));
+        self
+    }
+    /// Adds every item of an iterator to `hosts`.
+    pub fn extend_hosts(
+        &mut self,
+        hosts: impl std::iter::IntoIterator<Item = String>,
+    ) -> &mut Self {
+        std::iter::Extend::extend(&mut self.hosts, hosts);
+        self
+    }
+    /// Edits the builder of `limit`.
+    ///
+    /// This field is built by its own builder, whose errors are returned by `build`.
+    pub fn limit(&mut self, f: impl LimitBuilderEdit) -> &mut Self {
+        self.limit.__edit(f);
+        self
+    }
+    /// Returns the builder of `limit`.
+    pub fn limit_mut(&mut self) -> &mut LimitBuilder {
+        &mut self.limit
+    }
+    /// Unsets `name`, as in a new builder.
+    pub fn reset_name(&mut self) -> &mut Self {
+        self.name = std::option::Option::None;
+        self
+    }
+    /// Unsets `hosts`, as in a new builder.
+    pub fn reset_hosts(&mut self) -> &mut Self {
+        self.hosts = std::default::Default::default();
+        self
+    }
+    /// Unsets `limit`, as in a new builder.
+    pub fn reset_limit(&mut self) -> &mut Self {
+        self.limit = std::default::Default::default();
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.name = std::option::Option::None;
+        self.hosts = std::default::Default::default();
+        self.limit = std::default::Default::default();
+        self
+    }
+    /// Sets every field that is set in `other`, appending the items of its collections.
+    pub fn merge(&mut self, other: Self) -> &mut Self {
+        self.__merge(other);
+        self
+    }
+    #[doc(hidden)]
+    pub fn __merge(&mut self, other: Self) {
+        if other.name.is_some() {
+            self.name = other.name;
+        }
+        std::iter::Extend::extend(&mut self.hosts, other.hosts);
+        self.limit.__merge(other.limit);
+    }
+    /// Builds a [`Config`] from the fields set so far.
+    ///
+    /// Fails with [`ConfigBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Config, ConfigBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl ConfigBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Config, ConfigBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Config, ConfigBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.name.is_none() {
+            __errors.push(ConfigBuilderError::MissingName);
+        }
+        let limit = match self.limit.__build_ref() {
+            std::result::Result::Ok(value) => std::option::Option::Some(value),
+            std::result::Result::Err(__err) => {
+                __errors.push(ConfigBuilderError::InvalidLimit(__err));
+                std::option::Option::None
+            }
+        };
+        if let (std::option::Option::Some(name), std::option::Option::Some(limit))
+            = (self.name.clone(), limit) {
+            let hosts = self.hosts.clone();
+            if __errors.is_empty() {
+                std::result::Result::Ok(Config { name, hosts, limit })
+            } else {
+                std::result::Result::Err(ConfigBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ConfigBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.name.is_none() {
+            missing.push(std::format!("{}{}", path, "name"));
+        }
+        self.limit.__missing_fields(&std::format!("{}limit.", path), missing);
+    }
+}
+/// Closures editing a [`ConfigBuilder`], as taken by the setters of outer builders holding it.
+pub trait ConfigBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ConfigBuilder,
+    ) -> &'__edit mut ConfigBuilder {}
+impl<__F> ConfigBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ConfigBuilder,
+    ) -> &'__edit mut ConfigBuilder,
+{}
+impl std::convert::From<Config> for ConfigBuilder {
+    fn from(value: Config) -> Self {
+        let Config { name, hosts, limit } = value;
+        Self {
+            name: std::option::Option::Some(name),
+            hosts,
+            limit: std::convert::From::from(limit),
+        }
+    }
+}
+impl Config {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> ConfigBuilder
+    where
+        for<'__builder> Config: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`ConfigBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ConfigBuilderError {
+    /// `name` was not set.
+    MissingName,
+    /// The builder of `limit` failed.
+    InvalidLimit(LimitBuilderError),
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ConfigBuilderError>),
+}
+impl ConfigBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ConfigBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ConfigBuilderError::MissingName => {
+                std::write!(f, "`{}` is required", field("name"))
+            }
+            ConfigBuilderError::InvalidLimit(err) => {
+                err.__fmt_path(std::option::Option::Some(&field("limit")), f)
+            }
+            ConfigBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ConfigBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ConfigBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ConfigBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ConfigBuilderError {}
@@ -0,0 +1,18 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(serde, pattern = "owned")]
+pub struct Limit {
+    #[builder(field_attr(serde(rename = "maximum")))]
+    max: u16,
+}
+
+#[derive(Builder)]
+#[builder(serde)]
+pub struct Config {
+    name: String,
+    #[builder(each = "host")]
+    hosts: Vec<String>,
+    #[builder(sub_builder)]
+    limit: Limit,
+}
@@ -0,0 +1,492 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(getters, try_setter)]
+pub struct Server {
+    name: String,
+    port: u16,
+    #[builder(setter(custom))]
+    timeout: Option<u32>,
+}
+impl Server {
+    /// Returns a new [`ServerBuilder`].
+    pub fn builder() -> ServerBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Server`], returned by [`Server::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`ServerBuilderEdit`] closure.
+pub struct ServerBuilder {
+    name: std::option::Option<String>,
+    port: std::option::Option<u16>,
+    timeout: std::option::Option<u32>,
+}
+impl std::default::Default for ServerBuilder {
+    fn default() -> Self {
+        ServerBuilder {
+            name: std::option::Option::None,
+            port: std::option::Option::None,
+            timeout: std::option::Option::None,
+        }
+    }
+}
+impl ServerBuilder {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = std::option::Option::Some(name);
+        self
+    }
+    /// Sets `name` from a value converted with `TryInto`, failing with the conversion error.
+    pub fn try_name<__U: std::convert::TryInto<String>>(
+        &mut self,
+        name: __U,
+    ) -> std::result::Result<&mut Self, <__U as std::convert::TryInto<String>>::Error> {
+        let name = std::convert::TryInto::try_into(name)?;
+        self.name = std::option::Option::Some(name);
+        std::result::Result::Ok(self)
+    }
+    /// Sets `port`.
+    ///
+    /// This field is required.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = std::option::Option::Some(port);
+        self
+    }
+    /// Sets `port` from a value converted with `TryInto`, failing with the conversion error.
+    pub fn try_port<__U: std::convert::TryInto<u16>>(
+        &mut self,
+        port: __U,
+    ) -> std::result::Result<&mut Self, <__U as std::convert::TryInto<u16>>::Error> {
+        let port = std::convert::TryInto::try_into(port)?;
+        self.port = std::option::Option::Some(port);
+        std::result::Result::Ok(self)
+    }
+    /// Sets `timeout` from a value converted with `TryInto`, failing with the conversion error.
+    pub fn try_timeout<__U: std::convert::TryInto<u32>>(
+        &mut self,
+        timeout: __U,
+    ) -> std::result::Result<&mut Self, <__U as std::convert::TryInto<u32>>::Error> {
+        let timeout = std::convert::TryInto::try_into(timeout)?;
+        self.timeout = std::option::Option::Some(timeout);
+        std::result::Result::Ok(self)
+    }
+    /// Returns the value of `name` set so far.
+    pub fn name_ref(&self) -> std::option::Option<&String> {
+        self.name.as_ref()
+    }
+    /// Returns the value of `port` set so far.
+    pub fn port_ref(&self) -> std::option::Option<&u16> {
+        self.port.as_ref()
+    }
+    /// Returns the value of `timeout` set so far.
+    pub fn timeout_ref(&self) -> std::option::Option<&u32> {
+        self.timeout.as_ref()
+    }
+    /// Returns whether every required field is set, including those of
+    /// sub builders.
+    pub fn is_complete(&self) -> bool {
+        self.missing_fields().is_empty()
+    }
+    /// Returns the names of the required fields that are not set yet,
+    /// with the path of the field holding them for those of sub builders.
+    pub fn missing_fields(&self) -> std::vec::Vec<std::string::String> {
+        let mut missing = std::vec::Vec::new();
+        self.__missing_fields("", &mut missing);
+        missing
+    }
+    /// Unsets `name`, as in a new builder.
+    pub fn reset_name(&mut self) -> &mut Self {
+        self.name = std::option::Option::None;
+        self
+    }
+    /// Unsets `port`, as in a new builder.
+    pub fn reset_port(&mut self) -> &mut Self {
+        self.port = std::option::Option::None;
+        self
+    }
+    /// Unsets `timeout`, as in a new builder.
+    pub fn reset_timeout(&mut self) -> &mut Self {
+        self.timeout = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.name = std::option::Option::None;
+        self.port = std::option::Option::None;
+        self.timeout = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Server`] from the fields set so far.
+    ///
+    /// Fails with [`ServerBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Server, ServerBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl ServerBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Server, ServerBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Server, ServerBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.name.is_none() {
+            __errors.push(ServerBuilderError::MissingName);
+        }
+        if self.port.is_none() {
+            __errors.push(ServerBuilderError::MissingPort);
+        }
+        if let (std::option::Option::Some(name), std::option::Option::Some(port))
+            = (self.name.clone(), self.port.clone()) {
+            let timeout = self.timeout.clone();
+            if __errors.is_empty() {
+                std::result::Result::Ok(Server { name, port, timeout })
+            } else {
+                std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.name.is_none() {
+            missing.push(std::format!("{}{}", path, "name"));
+        }
+        if self.port.is_none() {
+            missing.push(std::format!("{}{}", path, "port"));
+        }
+    }
+}
+/// Closures editing a [`ServerBuilder`], as taken by the setters of outer builders holding it.
+pub trait ServerBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ServerBuilder,
+    ) -> &'__edit mut ServerBuilder {}
+impl<__F> ServerBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ServerBuilder,
+    ) -> &'__edit mut ServerBuilder,
+{}
+impl std::convert::From<Server> for ServerBuilder {
+    fn from(value: Server) -> Self {
+        let Server { name, port, timeout } = value;
+        Self {
+            name: std::option::Option::Some(name),
+            port: std::option::Option::Some(port),
+            timeout,
+        }
+    }
+}
+impl Server {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> ServerBuilder
+    where
+        for<'__builder> Server: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`ServerBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ServerBuilderError {
+    /// `name` was not set.
+    MissingName,
+    /// `port` was not set.
+    MissingPort,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ServerBuilderError>),
+}
+impl ServerBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ServerBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ServerBuilderError::MissingName => {
+                std::write!(f, "`{}` is required", field("name"))
+            }
+            ServerBuilderError::MissingPort => {
+                std::write!(f, "`{}` is required", field("port"))
+            }
+            ServerBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ServerBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ServerBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ServerBuilderError {}
+#[derive(Builder)]
+pub struct Limit {
+    #[builder(getters)]
+    max: u16,
+    #[builder(try_setter)]
+    min: u16,
+}
+impl Limit {
+    /// Returns a new [`LimitBuilder`].
+    pub fn builder() -> LimitBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Limit`], returned by [`Limit::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`LimitBuilderEdit`] closure.
+pub struct LimitBuilder {
+    max: std::option::Option<u16>,
+    min: std::option::Option<u16>,
+}
+impl std::default::Default for LimitBuilder {
+    fn default() -> Self {
+        LimitBuilder {
+            max: std::option::Option::None,
+            min: std::option::Option::None,
+        }
+    }
+}
+impl LimitBuilder {
+    /// Sets `max`.
+    ///
+    /// This field is required.
+    pub fn max(&mut self, max: u16) -> &mut Self {
+        self.max = std::option::Option::Some(max);
+        self
+    }
+    /// Sets `min`.
+    ///
+    /// This field is required.
+    pub fn min(&mut self, min: u16) -> &mut Self {
+        self.min = std::option::Option::Some(min);
+        self
+    }
+    /// Sets `min` from a value converted with `TryInto`, failing with the conversion error.
+    pub fn try_min<__U: std::convert::TryInto<u16>>(
+        &mut self,
+        min: __U,
+    ) -> std::result::Result<&mut Self, <__U as std::convert::TryInto<u16>>::Error> {
+        let min = std::convert::TryInto::try_into(min)?;
+        self.min = std::option::Option::Some(min);
+        std::result::Result::Ok(self)
+    }
+    /// Returns the value of `max` set so far.
+    pub fn max_ref(&self) -> std::option::Option<&u16> {
+        self.max.as_ref()
+    }
+    /// Unsets `max`, as in a new builder.
+    pub fn reset_max(&mut self) -> &mut Self {
+        self.max = std::option::Option::None;
+        self
+    }
+    /// Unsets `min`, as in a new builder.
+    pub fn reset_min(&mut self) -> &mut Self {
+        self.min = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.max = std::option::Option::None;
+        self.min = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Limit`] from the fields set so far.
+    ///
+    /// Fails with [`LimitBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Limit, LimitBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl LimitBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Limit, LimitBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Limit, LimitBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.max.is_none() {
+            __errors.push(LimitBuilderError::MissingMax);
+        }
+        if self.min.is_none() {
+            __errors.push(LimitBuilderError::MissingMin);
+        }
+        if let (std::option::Option::Some(max), std::option::Option::Some(min))
+            = (self.max.clone(), self.min.clone()) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Limit { max, min })
+            } else {
+                std::result::Result::Err(LimitBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(LimitBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.max.is_none() {
+            missing.push(std::format!("{}{}", path, "max"));
+        }
+        if self.min.is_none() {
+            missing.push(std::format!("{}{}", path, "min"));
+        }
+    }
+}
+/// Closures editing a [`LimitBuilder`], as taken by the setters of outer builders holding it.
+pub trait LimitBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut LimitBuilder,
+    ) -> &'__edit mut LimitBuilder {}
+impl<__F> LimitBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut LimitBuilder,
+    ) -> &'__edit mut LimitBuilder,
+{}
+impl std::convert::From<Limit> for LimitBuilder {
+    fn from(value: Limit) -> Self {
+        let Limit { max, min } = value;
+        Self {
+            max: std::option::Option::Some(max),
+            min: std::option::Option::Some(min),
+        }
+    }
+}
+impl Limit {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> LimitBuilder
+    where
+        for<'__builder> Limit: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`LimitBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum LimitBuilderError {
+    /// `max` was not set.
+    MissingMax,
+    /// `min` was not set.
+    MissingMin,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<LimitBuilderError>),
+}
+impl LimitBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            LimitBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            LimitBuilderError::MissingMax => {
+                std::write!(f, "`{}` is required", field("max"))
+            }
+            LimitBuilderError::MissingMin => {
+                std::write!(f, "`{}` is required", field("min"))
+            }
+            LimitBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            LimitBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            LimitBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for LimitBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for LimitBuilderError {}
@@ -0,0 +1,18 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(getters, try_setter)]
+pub struct Server {
+    name: String,
+    port: u16,
+    #[builder(setter(custom))]
+    timeout: Option<u32>,
+}
+
+#[derive(Builder)]
+pub struct Limit {
+    #[builder(getters)]
+    max: u16,
+    #[builder(try_setter)]
+    min: u16,
+}
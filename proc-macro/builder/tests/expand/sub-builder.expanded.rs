@@ -0,0 +1,391 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+pub struct Limits {
+    max: u32,
+}
+impl Limits {
+    /// Returns a new [`LimitsBuilder`].
+    pub fn builder() -> LimitsBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Limits`], returned by [`Limits::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`LimitsBuilderEdit`] closure.
+pub struct LimitsBuilder {
+    max: std::option::Option<u32>,
+}
+impl std::default::Default for LimitsBuilder {
+    fn default() -> Self {
+        LimitsBuilder {
+            max: std::option::Option::None,
+        }
+    }
+}
+impl LimitsBuilder {
+    /// Sets `max`.
+    ///
+    /// This field is required.
+    pub fn max(&mut self, max: u32) -> &mut Self {
+        self.max = std::option::Option::Some(max);
+        self
+    }
+    /// Unsets `max`, as in a new builder.
+    pub fn reset_max(&mut self) -> &mut Self {
+        self.max = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.max = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Limits`] from the fields set so far.
+    ///
+    /// Fails with [`LimitsBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Limits, LimitsBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl LimitsBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Limits, LimitsBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Limits, LimitsBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.max.is_none() {
+            __errors.push(LimitsBuilderError::MissingMax);
+        }
+        if let (std::option::Option::Some(max),) = (self.max.clone(),) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Limits { max })
+            } else {
+                std::result::Result::Err(LimitsBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(LimitsBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.max.is_none() {
+            missing.push(std::format!("{}{}", path, "max"));
+        }
+    }
+}
+/// Closures editing a [`LimitsBuilder`], as taken by the setters of outer builders holding it.
+pub trait LimitsBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut LimitsBuilder,
+    ) -> &'__edit mut LimitsBuilder {}
+impl<__F> LimitsBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut LimitsBuilder,
+    ) -> &'__edit mut LimitsBuilder,
+{}
+impl std::convert::From<Limits> for LimitsBuilder {
+    fn from(value: Limits) -> Self {
+        let Limits { max } = value;
+        Self {
+            max: std::option::Option::Some(max),
+        }
+    }
+}
+impl Limits {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> LimitsBuilder
+    where
+        for<'__builder> Limits: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`LimitsBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum LimitsBuilderError {
+    /// `max` was not set.
+    MissingMax,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<LimitsBuilderError>),
+}
+impl LimitsBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            LimitsBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            LimitsBuilderError::MissingMax => {
+                std::write!(f, "`{}` is required", field("max"))
+            }
+            LimitsBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            LimitsBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            LimitsBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for LimitsBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for LimitsBuilderError {}
+#[derive(Builder)]
+pub struct Server {
+    name: String,
+    #[builder(sub_builder)]
+    limits: Limits,
+}
+impl Server {
+    /// Returns a new [`ServerBuilder`].
+    pub fn builder() -> ServerBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Server`], returned by [`Server::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`ServerBuilderEdit`] closure.
+pub struct ServerBuilder {
+    name: std::option::Option<String>,
+    limits: LimitsBuilder,
+}
+impl std::default::Default for ServerBuilder {
+    fn default() -> Self {
+        ServerBuilder {
+            name: std::option::Option::None,
+            limits: std::default::Default::default(),
+        }
+    }
+}
+impl ServerBuilder {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = std::option::Option::Some(name);
+        self
+    }
+    /// Edits the builder of `limits`.
+    ///
+    /// This field is built by its own builder, whose errors are returned by `build`.
+    pub fn limits(&mut self, f: impl LimitsBuilderEdit) -> &mut Self {
+        self.limits.__edit(f);
+        self
+    }
+    /// Returns the builder of `limits`.
+    pub fn limits_mut(&mut self) -> &mut LimitsBuilder {
+        &mut self.limits
+    }
+    /// Unsets `name`, as in a new builder.
+    pub fn reset_name(&mut self) -> &mut Self {
+        self.name = std::option::Option::None;
+        self
+    }
+    /// Unsets `limits`, as in a new builder.
+    pub fn reset_limits(&mut self) -> &mut Self {
+        self.limits = std::default::Default::default();
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.name = std::option::Option::None;
+        self.limits = std::default::Default::default();
+        self
+    }
+    /// Builds a [`Server`] from the fields set so far.
+    ///
+    /// Fails with [`ServerBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Server, ServerBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl ServerBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Server, ServerBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Server, ServerBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        if self.name.is_none() {
+            __errors.push(ServerBuilderError::MissingName);
+        }
+        let limits = match self.limits.__build_ref() {
+            std::result::Result::Ok(value) => std::option::Option::Some(value),
+            std::result::Result::Err(__err) => {
+                __errors.push(ServerBuilderError::InvalidLimits(__err));
+                std::option::Option::None
+            }
+        };
+        if let (std::option::Option::Some(name), std::option::Option::Some(limits))
+            = (self.name.clone(), limits) {
+            if __errors.is_empty() {
+                std::result::Result::Ok(Server { name, limits })
+            } else {
+                std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.name.is_none() {
+            missing.push(std::format!("{}{}", path, "name"));
+        }
+        self.limits.__missing_fields(&std::format!("{}limits.", path), missing);
+    }
+}
+/// Closures editing a [`ServerBuilder`], as taken by the setters of outer builders holding it.
+pub trait ServerBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ServerBuilder,
+    ) -> &'__edit mut ServerBuilder {}
+impl<__F> ServerBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ServerBuilder,
+    ) -> &'__edit mut ServerBuilder,
+{}
+impl std::convert::From<Server> for ServerBuilder {
+    fn from(value: Server) -> Self {
+        let Server { name, limits } = value;
+        Self {
+            name: std::option::Option::Some(name),
+            limits: std::convert::From::from(limits),
+        }
+    }
+}
+impl Server {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> ServerBuilder
+    where
+        for<'__builder> Server: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`ServerBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ServerBuilderError {
+    /// `name` was not set.
+    MissingName,
+    /// The builder of `limits` failed.
+    InvalidLimits(LimitsBuilderError),
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ServerBuilderError>),
+}
+impl ServerBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ServerBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ServerBuilderError::MissingName => {
+                std::write!(f, "`{}` is required", field("name"))
+            }
+            ServerBuilderError::InvalidLimits(err) => {
+                err.__fmt_path(std::option::Option::Some(&field("limits")), f)
+            }
+            ServerBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ServerBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ServerBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ServerBuilderError {}
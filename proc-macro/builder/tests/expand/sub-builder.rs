@@ -0,0 +1,13 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Limits {
+    max: u32,
+}
+
+#[derive(Builder)]
+pub struct Server {
+    name: String,
+    #[builder(sub_builder)]
+    limits: Limits,
+}
@@ -0,0 +1,142 @@
+use derive_builder::Builder;
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    timeout: u32,
+    #[builder(default = "3")]
+    retries: u8,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+}
+impl Job {
+    /// Returns a new [`JobBuilder`].
+    pub fn builder() -> JobBuilder<(), ()> {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Job`], returned by [`Job::builder`].
+pub struct JobBuilder<__Name, __Timeout> {
+    name: __Name,
+    timeout: __Timeout,
+    retries: std::option::Option<u8>,
+    tags: Vec<String>,
+    __marker: std::marker::PhantomData<fn() -> Job>,
+}
+impl std::default::Default for JobBuilder<(), ()> {
+    fn default() -> Self {
+        JobBuilder {
+            name: (),
+            timeout: (),
+            retries: std::option::Option::None,
+            tags: std::default::Default::default(),
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<__Name, __Timeout> JobBuilder<__Name, __Timeout> {
+    /// Sets `name`.
+    ///
+    /// This field is required.
+    pub fn name(self, name: String) -> JobBuilder<(String,), __Timeout> {
+        JobBuilder {
+            name: (name,),
+            timeout: self.timeout,
+            retries: self.retries,
+            tags: self.tags,
+            __marker: std::marker::PhantomData,
+        }
+    }
+    /// Sets `timeout`.
+    ///
+    /// This field is required.
+    pub fn timeout(self, timeout: u32) -> JobBuilder<__Name, (u32,)> {
+        JobBuilder {
+            name: self.name,
+            timeout: (timeout,),
+            retries: self.retries,
+            tags: self.tags,
+            __marker: std::marker::PhantomData,
+        }
+    }
+    /// Sets `retries`.
+    ///
+    /// This field is optional, a default is used when it is not set.
+    pub fn retries(mut self, retries: u8) -> Self {
+        self.retries = std::option::Option::Some(retries);
+        self
+    }
+    /// Unsets `retries`, as in a new builder.
+    pub fn reset_retries(mut self) -> Self {
+        self.retries = std::option::Option::None;
+        self
+    }
+    /// Sets `tags`.
+    ///
+    /// This field is optional.
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+    /// Adds an item to `tags`.
+    pub fn tag(mut self, tag: String) -> Self {
+        std::iter::Extend::extend(&mut self.tags, std::iter::once(tag));
+        self
+    }
+    /// Adds every item of an iterator to `tags`.
+    pub fn extend_tags(
+        mut self,
+        tags: impl std::iter::IntoIterator<Item = String>,
+    ) -> Self {
+        std::iter::Extend::extend(&mut self.tags, tags);
+        self
+    }
+    /// Unsets `tags`, as in a new builder.
+    pub fn reset_tags(mut self) -> Self {
+        self.tags = std::default::Default::default();
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(self) -> JobBuilder<(), ()> {
+        std::default::Default::default()
+    }
+}
+impl JobBuilder<(String,), (u32,)> {
+    /// Builds a [`Job`] from the fields set so far.
+    pub fn build(self) -> Job {
+        let name = self.name.0;
+        let timeout = self.timeout.0;
+        let retries = match self.retries {
+            std::option::Option::Some(x) => x,
+            std::option::Option::None => 3,
+        };
+        let tags = self.tags;
+        Job {
+            name,
+            timeout,
+            retries,
+            tags,
+        }
+    }
+}
+impl std::convert::From<Job> for JobBuilder<(String,), (u32,)> {
+    fn from(value: Job) -> Self {
+        let Job { name, timeout, retries, tags } = value;
+        Self {
+            name: (name,),
+            timeout: (timeout,),
+            retries: std::option::Option::Some(retries),
+            tags,
+            __marker: std::marker::PhantomData,
+        }
+    }
+}
+impl Job {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> JobBuilder<(String,), (u32,)>
+    where
+        for<'__builder> Job: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
@@ -0,0 +1,12 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    timeout: u32,
+    #[builder(default = "3")]
+    retries: u8,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+}
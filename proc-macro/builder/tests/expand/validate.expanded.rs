@@ -0,0 +1,218 @@
+use derive_builder::Builder;
+fn non_zero(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        return Err("port must not be 0".to_owned());
+    }
+    Ok(())
+}
+fn check(builder: &ServerBuilder) -> Result<(), String> {
+    match builder.port {
+        Some(80) => Err("port 80 is reserved".to_owned()),
+        _ => Ok(()),
+    }
+}
+#[derive(Builder)]
+#[builder(build_fn(validate = "check"))]
+pub struct Server {
+    #[builder(validate = "non_zero")]
+    port: u16,
+}
+impl Server {
+    /// Returns a new [`ServerBuilder`].
+    pub fn builder() -> ServerBuilder {
+        std::default::Default::default()
+    }
+}
+/// Builder for [`Server`], returned by [`Server::builder`].
+///
+/// Other builders can hold it with `#[builder(sub_builder)]`, editing it with a [`ServerBuilderEdit`] closure.
+pub struct ServerBuilder {
+    port: std::option::Option<u16>,
+}
+impl std::default::Default for ServerBuilder {
+    fn default() -> Self {
+        ServerBuilder {
+            port: std::option::Option::None,
+        }
+    }
+}
+impl ServerBuilder {
+    /// Sets `port`.
+    ///
+    /// This field is required.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = std::option::Option::Some(port);
+        self
+    }
+    /// Unsets `port`, as in a new builder.
+    pub fn reset_port(&mut self) -> &mut Self {
+        self.port = std::option::Option::None;
+        self
+    }
+    /// Unsets every field, as in a new builder.
+    pub fn clear(&mut self) -> &mut Self {
+        self.port = std::option::Option::None;
+        self
+    }
+    /// Builds a [`Server`] from the fields set so far.
+    ///
+    /// Fails with [`ServerBuilderError`] when a required field is not set or a value is rejected.
+    pub fn build(&mut self) -> std::result::Result<Server, ServerBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __edit(&mut self, f: impl ServerBuilderEdit) {
+        f(self);
+    }
+    #[doc(hidden)]
+    pub fn __build_owned(self) -> std::result::Result<Server, ServerBuilderError> {
+        self.__build_ref()
+    }
+    #[doc(hidden)]
+    pub fn __build_ref(&self) -> std::result::Result<Server, ServerBuilderError> {
+        let mut __errors = std::vec::Vec::new();
+        let __builder: &Self = &self;
+        if let std::result::Result::Err(__err) = check(__builder) {
+            __errors
+                .push(ServerBuilderError::Validation {
+                    field: std::option::Option::None,
+                    message: std::string::ToString::to_string(&__err),
+                });
+        }
+        if self.port.is_none() {
+            __errors.push(ServerBuilderError::MissingPort);
+        }
+        if self.port.is_none() {
+            if let std::option::Option::Some(__value) = &self.port {
+                if let std::result::Result::Err(__err) = non_zero(__value) {
+                    __errors
+                        .push(ServerBuilderError::Validation {
+                            field: std::option::Option::Some("port"),
+                            message: std::string::ToString::to_string(&__err),
+                        });
+                }
+            }
+        }
+        if let (std::option::Option::Some(port),) = (self.port.clone(),) {
+            if let std::result::Result::Err(__err) = non_zero(&port) {
+                __errors
+                    .push(ServerBuilderError::Validation {
+                        field: std::option::Option::Some("port"),
+                        message: std::string::ToString::to_string(&__err),
+                    });
+            }
+            if __errors.is_empty() {
+                std::result::Result::Ok(Server { port })
+            } else {
+                std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+            }
+        } else {
+            std::result::Result::Err(ServerBuilderError::from_errors(__errors))
+        }
+    }
+    #[doc(hidden)]
+    pub fn __missing_fields(
+        &self,
+        path: &str,
+        missing: &mut std::vec::Vec<std::string::String>,
+    ) {
+        if self.port.is_none() {
+            missing.push(std::format!("{}{}", path, "port"));
+        }
+    }
+}
+/// Closures editing a [`ServerBuilder`], as taken by the setters of outer builders holding it.
+pub trait ServerBuilderEdit: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ServerBuilder,
+    ) -> &'__edit mut ServerBuilder {}
+impl<__F> ServerBuilderEdit for __F
+where
+    __F: for<'__edit> std::ops::FnOnce(
+        &'__edit mut ServerBuilder,
+    ) -> &'__edit mut ServerBuilder,
+{}
+impl std::convert::From<Server> for ServerBuilder {
+    fn from(value: Server) -> Self {
+        let Server { port } = value;
+        Self {
+            port: std::option::Option::Some(port),
+        }
+    }
+}
+impl Server {
+    /// Returns a builder with every field set from `self`.
+    pub fn to_builder(&self) -> ServerBuilder
+    where
+        for<'__builder> Server: std::clone::Clone,
+    {
+        std::convert::From::from(std::clone::Clone::clone(self))
+    }
+}
+/// Error returned by [`ServerBuilder::build`].
+#[derive(std::fmt::Debug, std::clone::Clone, std::cmp::PartialEq, std::cmp::Eq)]
+pub enum ServerBuilderError {
+    /// `port` was not set.
+    MissingPort,
+    /// A validator rejected a value.
+    Validation {
+        /// The field whose value was rejected, or `None` for the
+        /// validator of the whole builder.
+        field: std::option::Option<&'static str>,
+        /// The message of the validator.
+        message: std::string::String,
+    },
+    /// Several errors, in the order they were found.
+    Multiple(std::vec::Vec<ServerBuilderError>),
+}
+impl ServerBuilderError {
+    fn from_errors(mut errors: std::vec::Vec<Self>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            ServerBuilderError::Multiple(errors)
+        }
+    }
+    #[doc(hidden)]
+    pub fn __fmt_path(
+        &self,
+        path: std::option::Option<&str>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let field = |name: &str| match path {
+            std::option::Option::Some(path) => std::format!("{}.{}", path, name),
+            std::option::Option::None => std::string::ToString::to_string(name),
+        };
+        match self {
+            ServerBuilderError::MissingPort => {
+                std::write!(f, "`{}` is required", field("port"))
+            }
+            ServerBuilderError::Validation {
+                field: std::option::Option::Some(name),
+                message,
+            } => std::write!(f, "invalid `{}`: {}", field(name), message),
+            ServerBuilderError::Validation { message, .. } => {
+                match path {
+                    std::option::Option::Some(path) => {
+                        std::write!(f, "invalid `{}`: {}", path, message)
+                    }
+                    std::option::Option::None => f.write_str(message),
+                }
+            }
+            ServerBuilderError::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    error.__fmt_path(path, f)?;
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt_path(std::option::Option::None, f)
+    }
+}
+impl std::error::Error for ServerBuilderError {}
@@ -0,0 +1,22 @@
+use derive_builder::Builder;
+
+fn non_zero(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        return Err("port must not be 0".to_owned());
+    }
+    Ok(())
+}
+
+fn check(builder: &ServerBuilder) -> Result<(), String> {
+    match builder.port {
+        Some(80) => Err("port 80 is reserved".to_owned()),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Builder)]
+#[builder(build_fn(validate = "check"))]
+pub struct Server {
+    #[builder(validate = "non_zero")]
+    port: u16,
+}
@@ -10,4 +10,37 @@ fn tests() {
     t.pass("tests/07-repeated-field.rs");
     t.compile_fail("tests/08-unrecognized-attribute.rs");
     t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-typestate.rs");
+    t.compile_fail("tests/11-typestate-missing-field.rs");
+    t.pass("tests/12-builder-error.rs");
+    t.pass("tests/13-generics.rs");
+    t.pass("tests/14-default.rs");
+    t.pass("tests/15-pattern.rs");
+    t.compile_fail("tests/16-typestate-pattern.rs");
+    t.pass("tests/17-setter-into.rs");
+    t.pass("tests/18-validate.rs");
+    t.pass("tests/19-tuple-and-enum.rs");
+    t.compile_fail("tests/20-attribute-errors.rs");
+    t.pass("tests/21-std-paths.rs");
+    t.pass("tests/22-each-collections.rs");
+    t.pass("tests/23-setter-name.rs");
+    t.compile_fail("tests/24-setter-clash.rs");
+    t.pass("tests/25-builder-customisation.rs");
+    t.pass("tests/26-reset-and-seed.rs");
+    t.pass("tests/27-getters.rs");
+    t.pass("tests/28-skip-and-compute.rs");
+    t.compile_fail("tests/29-compute-conflict.rs");
+    t.pass("tests/30-custom-and-try-setter.rs");
+    #[cfg(feature = "serde")]
+    t.pass("tests/31-serde.rs");
+    t.pass("tests/32-sub-builder.rs");
+    t.pass("tests/33-no-std.rs");
+    t.pass("tests/34-docs.rs");
+    t.pass("tests/35-field-attributes.rs");
+    t.pass("tests/36-const.rs");
+    t.compile_fail("tests/37-const-missing-field.rs");
+    t.pass("tests/38-required-ctor.rs");
+    t.compile_fail("tests/39-method-clash.rs");
+    t.compile_fail("tests/40-variant-clash.rs");
+    t.compile_fail("tests/41-each-not-collection.rs");
 }